@@ -0,0 +1,777 @@
+//! iced style catalogs for [`ModernTheme`].
+//!
+//! With `ModernTheme` as the theme of an application, every widget is
+//! Modern-styled without a `.style()` call, including widgets built by
+//! third-party code. Each widget's class picks a variant:
+//!
+//! ```ignore
+//! button("Delete").class(style::Button::Danger);
+//! button("Remove").class((style::Button::Danger, style::Emphasis::Outlined));
+//! container(content).class(style::Container::Card);
+//! text("Saved").class(style::Text::Success);
+//! text_input("Search", &query).class(style::TextInput::Search);
+//! ```
+//!
+//! Any `Modern` style function can be used as a class as well, e.g.
+//! `.class(Modern::teal_button())`, and `.style()` takes closures over
+//! `&ModernTheme` as usual.
+
+use iced::overlay::menu;
+use iced::widget::{
+    button, checkbox, container, pane_grid, pick_list, progress_bar, radio, rule, scrollable,
+    slider, text, text_editor, text_input, toggler,
+};
+#[cfg(feature = "svg")]
+use iced::widget::svg;
+use iced::Theme;
+
+use crate::styles::{style, TintedButtonColor};
+use crate::theme::{Modern, ModernTheme, ValidationState};
+
+/// Style class of a button
+pub enum ButtonClass<'a> {
+    /// A Modern button variant
+    Button(style::Button),
+    /// A Modern button role at an emphasis level
+    Emphasized(style::Button, style::Emphasis),
+    /// A Modern tinted button
+    Tinted(TintedButtonColor),
+    /// A style function of the iced theme, such as `Modern::teal_button()`
+    Themed(Box<dyn Fn(&Theme, button::Status) -> button::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(button::StyleFn<'a, ModernTheme>),
+}
+
+impl From<style::Button> for ButtonClass<'_> {
+    fn from(style: style::Button) -> Self {
+        ButtonClass::Button(style)
+    }
+}
+
+impl From<(style::Button, style::Emphasis)> for ButtonClass<'_> {
+    fn from((style, emphasis): (style::Button, style::Emphasis)) -> Self {
+        ButtonClass::Emphasized(style, emphasis)
+    }
+}
+
+impl From<TintedButtonColor> for ButtonClass<'_> {
+    fn from(color: TintedButtonColor) -> Self {
+        ButtonClass::Tinted(color)
+    }
+}
+
+impl<'a, F> From<F> for ButtonClass<'a>
+where
+    F: Fn(&Theme, button::Status) -> button::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        ButtonClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<button::StyleFn<'a, ModernTheme>> for ButtonClass<'a> {
+    fn from(style: button::StyleFn<'a, ModernTheme>) -> Self {
+        ButtonClass::Custom(style)
+    }
+}
+
+impl button::Catalog for ModernTheme {
+    type Class<'a> = ButtonClass<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        ButtonClass::Button(style::Button::default())
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: button::Status) -> button::Style {
+        match class {
+            ButtonClass::Button(style) => Modern::button(*style)(self.as_theme(), status),
+            ButtonClass::Emphasized(style, emphasis) => {
+                Modern::emphasized_button(*style, *emphasis)(self.as_theme(), status)
+            }
+            ButtonClass::Tinted(color) => Modern::tinted_button(*color)(self.as_theme(), status),
+            ButtonClass::Themed(style) => style(self.as_theme(), status),
+            ButtonClass::Custom(style) => style(self, status),
+        }
+    }
+}
+
+/// Style class of a container
+pub enum ContainerClass<'a> {
+    /// A Modern container variant
+    Container(style::Container),
+    /// A style function of the iced theme, such as `Modern::toolbar_container()`
+    Themed(Box<dyn Fn(&Theme) -> container::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(container::StyleFn<'a, ModernTheme>),
+}
+
+impl From<style::Container> for ContainerClass<'_> {
+    fn from(style: style::Container) -> Self {
+        ContainerClass::Container(style)
+    }
+}
+
+impl<'a, F> From<F> for ContainerClass<'a>
+where
+    F: Fn(&Theme) -> container::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        ContainerClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<container::StyleFn<'a, ModernTheme>> for ContainerClass<'a> {
+    fn from(style: container::StyleFn<'a, ModernTheme>) -> Self {
+        ContainerClass::Custom(style)
+    }
+}
+
+impl container::Catalog for ModernTheme {
+    type Class<'a> = ContainerClass<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        // Layout containers draw nothing and inherit their text color, so
+        // they don't override the labels of buttons they are placed in
+        ContainerClass::Themed(Box::new(container::transparent))
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> container::Style {
+        match class {
+            ContainerClass::Container(style) => Modern::container(*style)(self.as_theme()),
+            ContainerClass::Themed(style) => style(self.as_theme()),
+            ContainerClass::Custom(style) => style(self),
+        }
+    }
+}
+
+/// Style class of a text
+pub enum TextClass<'a> {
+    /// A Modern text variant
+    Text(style::Text),
+    /// A style function of the iced theme, such as `Modern::teal_text()`
+    Themed(Box<dyn Fn(&Theme) -> text::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(text::StyleFn<'a, ModernTheme>),
+}
+
+impl From<style::Text> for TextClass<'_> {
+    fn from(style: style::Text) -> Self {
+        TextClass::Text(style)
+    }
+}
+
+impl<'a, F> From<F> for TextClass<'a>
+where
+    F: Fn(&Theme) -> text::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        TextClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<text::StyleFn<'a, ModernTheme>> for TextClass<'a> {
+    fn from(style: text::StyleFn<'a, ModernTheme>) -> Self {
+        TextClass::Custom(style)
+    }
+}
+
+impl text::Catalog for ModernTheme {
+    type Class<'a> = TextClass<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        TextClass::Text(style::Text::default())
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> text::Style {
+        let theme = self.as_theme();
+
+        match class {
+            TextClass::Text(style::Text::Default) => text::Style::default(),
+            TextClass::Text(style::Text::Primary) => Modern::primary_text()(theme),
+            TextClass::Text(style::Text::Secondary) => Modern::secondary_text()(theme),
+            TextClass::Text(style::Text::Tertiary) => Modern::tertiary_text()(theme),
+            TextClass::Text(style::Text::Link) => Modern::link_text()(theme),
+            TextClass::Text(style::Text::Success) => Modern::success_text()(theme),
+            TextClass::Text(style::Text::Warning) => Modern::warning_text()(theme),
+            TextClass::Text(style::Text::Error) => Modern::error_text()(theme),
+            TextClass::Themed(style) => style(theme),
+            TextClass::Custom(style) => style(self),
+        }
+    }
+}
+
+/// Style class of a text input
+pub enum TextInputClass<'a> {
+    /// A Modern text input variant
+    TextInput(style::TextInput),
+    /// A style function of the iced theme, such as `Modern::conditional_text_input(state)`
+    Themed(Box<dyn Fn(&Theme, text_input::Status) -> text_input::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(text_input::StyleFn<'a, ModernTheme>),
+}
+
+impl From<style::TextInput> for TextInputClass<'_> {
+    fn from(style: style::TextInput) -> Self {
+        TextInputClass::TextInput(style)
+    }
+}
+
+impl<'a, F> From<F> for TextInputClass<'a>
+where
+    F: Fn(&Theme, text_input::Status) -> text_input::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        TextInputClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<text_input::StyleFn<'a, ModernTheme>> for TextInputClass<'a> {
+    fn from(style: text_input::StyleFn<'a, ModernTheme>) -> Self {
+        TextInputClass::Custom(style)
+    }
+}
+
+impl text_input::Catalog for ModernTheme {
+    type Class<'a> = TextInputClass<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        TextInputClass::TextInput(style::TextInput::default())
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: text_input::Status) -> text_input::Style {
+        let theme = self.as_theme();
+
+        match class {
+            TextInputClass::TextInput(style::TextInput::Standard) => Modern::text_input()(theme, status),
+            TextInputClass::TextInput(style::TextInput::Search) => Modern::search_input()(theme, status),
+            TextInputClass::TextInput(style::TextInput::Inline) => Modern::inline_text_input()(theme, status),
+            TextInputClass::TextInput(style::TextInput::Danger) => Modern::danger_text_input()(theme, status),
+            TextInputClass::TextInput(style::TextInput::Warning) => Modern::warning_text_input()(theme, status),
+            TextInputClass::Themed(style) => style(theme, status),
+            TextInputClass::Custom(style) => style(self, status),
+        }
+    }
+}
+
+/// Style class of a checkbox
+pub enum CheckboxClass<'a> {
+    /// A style function of the iced theme, `Modern::checkbox()` by default
+    Themed(Box<dyn Fn(&Theme, checkbox::Status) -> checkbox::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(checkbox::StyleFn<'a, ModernTheme>),
+}
+
+impl<'a, F> From<F> for CheckboxClass<'a>
+where
+    F: Fn(&Theme, checkbox::Status) -> checkbox::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        CheckboxClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<checkbox::StyleFn<'a, ModernTheme>> for CheckboxClass<'a> {
+    fn from(style: checkbox::StyleFn<'a, ModernTheme>) -> Self {
+        CheckboxClass::Custom(style)
+    }
+}
+
+impl checkbox::Catalog for ModernTheme {
+    type Class<'a> = CheckboxClass<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        CheckboxClass::Themed(Box::new(Modern::checkbox()))
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: checkbox::Status) -> checkbox::Style {
+        match class {
+            CheckboxClass::Themed(style) => style(self.as_theme(), status),
+            CheckboxClass::Custom(style) => style(self, status),
+        }
+    }
+}
+
+/// Style class of a radio button
+pub enum RadioClass<'a> {
+    /// A style function of the iced theme, `Modern::radio()` by default
+    Themed(Box<dyn Fn(&Theme, radio::Status) -> radio::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(radio::StyleFn<'a, ModernTheme>),
+}
+
+impl<'a, F> From<F> for RadioClass<'a>
+where
+    F: Fn(&Theme, radio::Status) -> radio::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        RadioClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<radio::StyleFn<'a, ModernTheme>> for RadioClass<'a> {
+    fn from(style: radio::StyleFn<'a, ModernTheme>) -> Self {
+        RadioClass::Custom(style)
+    }
+}
+
+impl radio::Catalog for ModernTheme {
+    type Class<'a> = RadioClass<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        RadioClass::Themed(Box::new(Modern::radio()))
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: radio::Status) -> radio::Style {
+        match class {
+            RadioClass::Themed(style) => style(self.as_theme(), status),
+            RadioClass::Custom(style) => style(self, status),
+        }
+    }
+}
+
+/// Style class of a pick list
+pub enum PickListClass<'a> {
+    /// A Modern pick list bordered by a validation state
+    Validation(ValidationState),
+    /// A style function of the iced theme, `Modern::pick_list()` by default
+    Themed(Box<dyn Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(pick_list::StyleFn<'a, ModernTheme>),
+}
+
+impl From<ValidationState> for PickListClass<'_> {
+    fn from(state: ValidationState) -> Self {
+        PickListClass::Validation(state)
+    }
+}
+
+impl<'a, F> From<F> for PickListClass<'a>
+where
+    F: Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        PickListClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<pick_list::StyleFn<'a, ModernTheme>> for PickListClass<'a> {
+    fn from(style: pick_list::StyleFn<'a, ModernTheme>) -> Self {
+        PickListClass::Custom(style)
+    }
+}
+
+impl pick_list::Catalog for ModernTheme {
+    type Class<'a> = PickListClass<'a>;
+
+    fn default<'a>() -> <Self as pick_list::Catalog>::Class<'a> {
+        PickListClass::Themed(Box::new(Modern::pick_list()))
+    }
+
+    fn style(
+        &self,
+        class: &<Self as pick_list::Catalog>::Class<'_>,
+        status: pick_list::Status,
+    ) -> pick_list::Style {
+        match class {
+            PickListClass::Validation(state) => {
+                Modern::conditional_pick_list(*state)(self.as_theme(), status)
+            }
+            PickListClass::Themed(style) => style(self.as_theme(), status),
+            PickListClass::Custom(style) => style(self, status),
+        }
+    }
+}
+
+/// Style class of a dropdown menu
+pub enum MenuClass<'a> {
+    /// A style function of the iced theme, `Modern::pick_list_menu()` by default
+    Themed(Box<dyn Fn(&Theme) -> menu::Style + 'a>),
+    /// A style function of the Modern theme
+    Custom(menu::StyleFn<'a, ModernTheme>),
+}
+
+impl<'a, F> From<F> for MenuClass<'a>
+where
+    F: Fn(&Theme) -> menu::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        MenuClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<menu::StyleFn<'a, ModernTheme>> for MenuClass<'a> {
+    fn from(style: menu::StyleFn<'a, ModernTheme>) -> Self {
+        MenuClass::Custom(style)
+    }
+}
+
+impl menu::Catalog for ModernTheme {
+    type Class<'a> = MenuClass<'a>;
+
+    fn default<'a>() -> <Self as menu::Catalog>::Class<'a> {
+        MenuClass::Themed(Box::new(Modern::pick_list_menu()))
+    }
+
+    fn style(&self, class: &<Self as menu::Catalog>::Class<'_>) -> menu::Style {
+        match class {
+            MenuClass::Themed(style) => style(self.as_theme()),
+            MenuClass::Custom(style) => style(self),
+        }
+    }
+}
+
+impl iced::widget::combo_box::Catalog for ModernTheme {}
+
+/// Style class of a scrollable
+pub enum ScrollableClass<'a> {
+    /// A style function of the iced theme, `Modern::scrollable()` by default
+    Themed(Box<dyn Fn(&Theme, scrollable::Status) -> scrollable::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(scrollable::StyleFn<'a, ModernTheme>),
+}
+
+impl<'a, F> From<F> for ScrollableClass<'a>
+where
+    F: Fn(&Theme, scrollable::Status) -> scrollable::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        ScrollableClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<scrollable::StyleFn<'a, ModernTheme>> for ScrollableClass<'a> {
+    fn from(style: scrollable::StyleFn<'a, ModernTheme>) -> Self {
+        ScrollableClass::Custom(style)
+    }
+}
+
+impl scrollable::Catalog for ModernTheme {
+    type Class<'a> = ScrollableClass<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        ScrollableClass::Themed(Box::new(Modern::scrollable()))
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: scrollable::Status) -> scrollable::Style {
+        match class {
+            ScrollableClass::Themed(style) => style(self.as_theme(), status),
+            ScrollableClass::Custom(style) => style(self, status),
+        }
+    }
+}
+
+/// Style class of a slider or vertical slider
+pub enum SliderClass<'a> {
+    /// A Modern slider filled with a system color
+    Tinted(TintedButtonColor),
+    /// A style function of the iced theme, `Modern::slider()` by default
+    Themed(Box<dyn Fn(&Theme, slider::Status) -> slider::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(slider::StyleFn<'a, ModernTheme>),
+}
+
+impl From<TintedButtonColor> for SliderClass<'_> {
+    fn from(color: TintedButtonColor) -> Self {
+        SliderClass::Tinted(color)
+    }
+}
+
+impl<'a, F> From<F> for SliderClass<'a>
+where
+    F: Fn(&Theme, slider::Status) -> slider::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        SliderClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<slider::StyleFn<'a, ModernTheme>> for SliderClass<'a> {
+    fn from(style: slider::StyleFn<'a, ModernTheme>) -> Self {
+        SliderClass::Custom(style)
+    }
+}
+
+impl slider::Catalog for ModernTheme {
+    type Class<'a> = SliderClass<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        SliderClass::Themed(Box::new(Modern::slider()))
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: slider::Status) -> slider::Style {
+        match class {
+            SliderClass::Tinted(color) => Modern::colored_slider(*color)(self.as_theme(), status),
+            SliderClass::Themed(style) => style(self.as_theme(), status),
+            SliderClass::Custom(style) => style(self, status),
+        }
+    }
+}
+
+/// Style class of a toggler
+pub enum TogglerClass<'a> {
+    /// A Modern toggler with a system color track
+    Tinted(TintedButtonColor),
+    /// A style function of the iced theme, `Modern::toggler()` by default
+    Themed(Box<dyn Fn(&Theme, toggler::Status) -> toggler::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(toggler::StyleFn<'a, ModernTheme>),
+}
+
+impl From<TintedButtonColor> for TogglerClass<'_> {
+    fn from(color: TintedButtonColor) -> Self {
+        TogglerClass::Tinted(color)
+    }
+}
+
+impl<'a, F> From<F> for TogglerClass<'a>
+where
+    F: Fn(&Theme, toggler::Status) -> toggler::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        TogglerClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<toggler::StyleFn<'a, ModernTheme>> for TogglerClass<'a> {
+    fn from(style: toggler::StyleFn<'a, ModernTheme>) -> Self {
+        TogglerClass::Custom(style)
+    }
+}
+
+impl toggler::Catalog for ModernTheme {
+    type Class<'a> = TogglerClass<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        TogglerClass::Themed(Box::new(Modern::toggler()))
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: toggler::Status) -> toggler::Style {
+        match class {
+            TogglerClass::Tinted(color) => Modern::colored_toggler(*color)(self.as_theme(), status),
+            TogglerClass::Themed(style) => style(self.as_theme(), status),
+            TogglerClass::Custom(style) => style(self, status),
+        }
+    }
+}
+
+/// Style class of a progress bar
+pub enum ProgressBarClass<'a> {
+    /// A Modern progress bar colored by a validation state
+    Validation(ValidationState),
+    /// A style function of the iced theme, `Modern::progress_bar()` by default
+    Themed(Box<dyn Fn(&Theme) -> progress_bar::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(progress_bar::StyleFn<'a, ModernTheme>),
+}
+
+impl From<ValidationState> for ProgressBarClass<'_> {
+    fn from(state: ValidationState) -> Self {
+        ProgressBarClass::Validation(state)
+    }
+}
+
+impl<'a, F> From<F> for ProgressBarClass<'a>
+where
+    F: Fn(&Theme) -> progress_bar::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        ProgressBarClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<progress_bar::StyleFn<'a, ModernTheme>> for ProgressBarClass<'a> {
+    fn from(style: progress_bar::StyleFn<'a, ModernTheme>) -> Self {
+        ProgressBarClass::Custom(style)
+    }
+}
+
+impl progress_bar::Catalog for ModernTheme {
+    type Class<'a> = ProgressBarClass<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        ProgressBarClass::Themed(Box::new(Modern::progress_bar()))
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> progress_bar::Style {
+        match class {
+            ProgressBarClass::Validation(state) => {
+                Modern::conditional_progress_bar(*state)(self.as_theme())
+            }
+            ProgressBarClass::Themed(style) => style(self.as_theme()),
+            ProgressBarClass::Custom(style) => style(self),
+        }
+    }
+}
+
+/// Style class of a rule
+pub enum RuleClass<'a> {
+    /// A style function of the iced theme, `Modern::rule()` by default
+    Themed(Box<dyn Fn(&Theme) -> rule::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(rule::StyleFn<'a, ModernTheme>),
+}
+
+impl<'a, F> From<F> for RuleClass<'a>
+where
+    F: Fn(&Theme) -> rule::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        RuleClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<rule::StyleFn<'a, ModernTheme>> for RuleClass<'a> {
+    fn from(style: rule::StyleFn<'a, ModernTheme>) -> Self {
+        RuleClass::Custom(style)
+    }
+}
+
+impl rule::Catalog for ModernTheme {
+    type Class<'a> = RuleClass<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        RuleClass::Themed(Box::new(Modern::rule()))
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> rule::Style {
+        match class {
+            RuleClass::Themed(style) => style(self.as_theme()),
+            RuleClass::Custom(style) => style(self),
+        }
+    }
+}
+
+/// Style class of a text editor
+pub enum TextEditorClass<'a> {
+    /// A Modern text editor bordered by a validation state
+    Validation(ValidationState),
+    /// A style function of the iced theme, `Modern::text_editor()` by default
+    Themed(Box<dyn Fn(&Theme, text_editor::Status) -> text_editor::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(text_editor::StyleFn<'a, ModernTheme>),
+}
+
+impl From<ValidationState> for TextEditorClass<'_> {
+    fn from(state: ValidationState) -> Self {
+        TextEditorClass::Validation(state)
+    }
+}
+
+impl<'a, F> From<F> for TextEditorClass<'a>
+where
+    F: Fn(&Theme, text_editor::Status) -> text_editor::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        TextEditorClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<text_editor::StyleFn<'a, ModernTheme>> for TextEditorClass<'a> {
+    fn from(style: text_editor::StyleFn<'a, ModernTheme>) -> Self {
+        TextEditorClass::Custom(style)
+    }
+}
+
+impl text_editor::Catalog for ModernTheme {
+    type Class<'a> = TextEditorClass<'a>;
+
+    fn default<'a>() -> <Self as text_editor::Catalog>::Class<'a> {
+        TextEditorClass::Themed(Box::new(Modern::text_editor()))
+    }
+
+    fn style(
+        &self,
+        class: &<Self as text_editor::Catalog>::Class<'_>,
+        status: text_editor::Status,
+    ) -> text_editor::Style {
+        match class {
+            TextEditorClass::Validation(state) => {
+                Modern::conditional_text_editor(*state)(self.as_theme(), status)
+            }
+            TextEditorClass::Themed(style) => style(self.as_theme(), status),
+            TextEditorClass::Custom(style) => style(self, status),
+        }
+    }
+}
+
+/// Style class of a pane grid
+pub enum PaneGridClass<'a> {
+    /// A style function of the iced theme, `Modern::pane_grid()` by default
+    Themed(Box<dyn Fn(&Theme) -> pane_grid::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(pane_grid::StyleFn<'a, ModernTheme>),
+}
+
+impl<'a, F> From<F> for PaneGridClass<'a>
+where
+    F: Fn(&Theme) -> pane_grid::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        PaneGridClass::Themed(Box::new(style))
+    }
+}
+
+impl<'a> From<pane_grid::StyleFn<'a, ModernTheme>> for PaneGridClass<'a> {
+    fn from(style: pane_grid::StyleFn<'a, ModernTheme>) -> Self {
+        PaneGridClass::Custom(style)
+    }
+}
+
+impl pane_grid::Catalog for ModernTheme {
+    type Class<'a> = PaneGridClass<'a>;
+
+    fn default<'a>() -> <Self as pane_grid::Catalog>::Class<'a> {
+        PaneGridClass::Themed(Box::new(Modern::pane_grid()))
+    }
+
+    fn style(&self, class: &<Self as pane_grid::Catalog>::Class<'_>) -> pane_grid::Style {
+        match class {
+            PaneGridClass::Themed(style) => style(self.as_theme()),
+            PaneGridClass::Custom(style) => style(self),
+        }
+    }
+}
+
+/// Style class of an SVG
+///
+/// SVGs keep their own colors unless a tint such as `Modern::svg()` is set.
+#[cfg(feature = "svg")]
+pub enum SvgClass<'a> {
+    /// A style function of the iced theme
+    Themed(Box<dyn Fn(&Theme, svg::Status) -> svg::Style + 'a>),
+    /// A style function of the Modern theme, as passed to `.style()`
+    Custom(svg::StyleFn<'a, ModernTheme>),
+}
+
+#[cfg(feature = "svg")]
+impl<'a, F> From<F> for SvgClass<'a>
+where
+    F: Fn(&Theme, svg::Status) -> svg::Style + 'a,
+{
+    fn from(style: F) -> Self {
+        SvgClass::Themed(Box::new(style))
+    }
+}
+
+#[cfg(feature = "svg")]
+impl<'a> From<svg::StyleFn<'a, ModernTheme>> for SvgClass<'a> {
+    fn from(style: svg::StyleFn<'a, ModernTheme>) -> Self {
+        SvgClass::Custom(style)
+    }
+}
+
+#[cfg(feature = "svg")]
+impl svg::Catalog for ModernTheme {
+    type Class<'a> = SvgClass<'a>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        SvgClass::Themed(Box::new(|_theme, _status| svg::Style::default()))
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: svg::Status) -> svg::Style {
+        match class {
+            SvgClass::Themed(style) => style(self.as_theme(), status),
+            SvgClass::Custom(style) => style(self, status),
+        }
+    }
+}
@@ -4,6 +4,11 @@
 //! organized into namespaces for both light and dark modes.
 
 use iced::Color;
+use palette::{Clamp, FromColor, IntoColor, IsWithinBounds, Mix, Oklab, Oklch, Srgb};
+use crate::contrast::relative_luminance;
+use crate::styles::Metrics;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock, Weak};
 
 /// Complete Modern system color palette
 pub mod colors {
@@ -238,6 +243,11 @@ pub const MODERN_RED_LIGHT: Color = colors::system::RED;
 pub const MODERN_RED_DARK: Color = colors::system::RED_DARK;
 
 /// Complete set of theme colors used across the Modern-styled UI
+///
+/// The defaults for each mode are available through [`ThemeColors::light`] and
+/// [`ThemeColors::dark`]. Custom palettes can be built with
+/// [`ThemeColors::builder`] and registered with `Modern::custom_theme`.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ThemeColors {
     // Primary colors
     pub blue: Color,
@@ -257,6 +267,9 @@ pub struct ThemeColors {
     
     // Element colors
     pub card_bg: Color,
+    pub sheet_bg: Color,
+    pub group_bg: Color,
+    pub sidebar_bg: Color,
     pub system_bg: Color,
     pub inactive_border: Color,
     
@@ -267,10 +280,15 @@ pub struct ThemeColors {
     pub input_bg: Color,
     pub input_border: Color,
     
+    // Accent colors
+    pub accent: Color,
+    pub focus_ring: Color,
+    
     // Additional colors
     pub separator: Color,
     pub selection: Color, 
     pub link: Color,
+    pub gray: Color,
     pub pink: Color,
     pub purple: Color,
     pub indigo: Color,
@@ -280,58 +298,657 @@ pub struct ThemeColors {
     pub brown: Color,
 }
 
-/// Helper function to determine if we're in dark mode
-pub fn is_dark_mode(theme: &iced::Theme) -> bool {
+/// Light or dark appearance of a theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "theme-files",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Mode {
+    /// Light backgrounds with dark text
+    #[default]
+    Light,
+    /// Dark backgrounds with light text
+    Dark,
+}
+
+impl Mode {
+    /// Whether this is the dark mode
+    pub fn is_dark(self) -> bool {
+        self == Mode::Dark
+    }
+
+    /// Infer the mode from a background color
+    ///
+    /// A background is considered dark when white text would contrast better
+    /// against it than black text, i.e. when its relative luminance is below ~0.18.
+    pub fn from_background(background: Color) -> Self {
+        if relative_luminance(background) < 0.179 {
+            Mode::Dark
+        } else {
+            Mode::Light
+        }
+    }
+}
+
+/// An accent color with its light and dark mode variants
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Accent {
+    /// Accent used in light mode
+    pub light: Color,
+    /// Accent used in dark mode
+    pub dark: Color,
+}
+
+impl Accent {
+    /// The default system blue accent
+    pub const SYSTEM: Accent = Accent::new(colors::system::BLUE, colors::system::BLUE_DARK);
+
+    /// Create an accent from explicit light and dark variants
+    pub const fn new(light: Color, dark: Color) -> Self {
+        Self { light, dark }
+    }
+
+    /// Create an accent from a single color, deriving the dark mode variant
+    ///
+    /// Like the system colors, the dark variant is slightly lifted towards
+    /// white so it keeps its vibrancy on dark backgrounds.
+    pub fn from_color(color: Color) -> Self {
+        let lift = |channel: f32| channel + (1.0 - channel) * 0.06;
+
+        Self {
+            light: color,
+            dark: Color {
+                r: lift(color.r),
+                g: lift(color.g),
+                b: lift(color.b),
+                a: color.a,
+            },
+        }
+    }
+
+    /// The accent variant for the given mode
+    pub fn for_mode(self, mode: Mode) -> Color {
+        match mode {
+            Mode::Light => self.light,
+            Mode::Dark => self.dark,
+        }
+    }
+}
+
+impl From<Color> for Accent {
+    fn from(color: Color) -> Self {
+        Self::from_color(color)
+    }
+}
+
+/// Settings registered for a custom theme
+#[derive(Debug, Clone, Copy, Default)]
+struct RegisteredTheme {
+    mode: Option<Mode>,
+    accent: Option<Accent>,
+    colors: Option<ThemeColors>,
+    metrics: Option<Metrics>,
+    high_contrast: bool,
+}
+
+/// The settings of a custom theme and a handle to check it is still alive
+struct Registration {
+    theme: Weak<iced::theme::Custom>,
+    settings: RegisteredTheme,
+}
+
+/// Settings registered for custom themes, keyed by [`theme_key`]
+fn registered_themes() -> &'static RwLock<HashMap<usize, Registration>> {
+    static THEMES: OnceLock<RwLock<HashMap<usize, Registration>>> = OnceLock::new();
+    THEMES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// A key unique to one built custom theme and shared by its clones
+///
+/// The registry holds a weak handle to every registered theme, so the address
+/// can't be reused while its entry exists.
+fn theme_key(custom: &Arc<iced::theme::Custom>) -> usize {
+    Arc::as_ptr(custom) as usize
+}
+
+/// Registry size from which entries of dropped themes are swept
+const SWEEP_THRESHOLD: usize = 64;
+
+/// Update the settings registered for a custom theme
+///
+/// Entries of dropped themes are swept whenever the registry reaches a power
+/// of two from [`SWEEP_THRESHOLD`] on, so sweeping stays amortized O(1) even
+/// when a theme is rebuilt every frame.
+fn update_registered(theme: &iced::Theme, update: impl FnOnce(&mut RegisteredTheme)) {
+    let iced::Theme::Custom(custom) = theme else {
+        return;
+    };
+
+    if let Ok(mut themes) = registered_themes().write() {
+        if themes.len() >= SWEEP_THRESHOLD && themes.len().is_power_of_two() {
+            themes.retain(|_, registration| registration.theme.strong_count() > 0);
+        }
+
+        let registration = themes.entry(theme_key(custom)).or_insert_with(|| Registration {
+            theme: Arc::downgrade(custom),
+            settings: RegisteredTheme::default(),
+        });
+        update(&mut registration.settings);
+    }
+}
+
+/// Look up the settings registered for a theme, if it is a custom theme
+fn registered(theme: &iced::Theme) -> Option<RegisteredTheme> {
+    match theme {
+        iced::Theme::Custom(custom) => registered_themes()
+            .read()
+            .ok()
+            .and_then(|themes| themes.get(&theme_key(custom)).map(|registration| registration.settings)),
+        _ => None,
+    }
+}
+
+/// Register every setting of a custom theme at once
+pub(crate) fn register_theme(
+    theme: &iced::Theme,
+    mode: Mode,
+    accent: Option<Accent>,
+    colors: Option<ThemeColors>,
+    metrics: Option<Metrics>,
+    high_contrast: bool,
+) {
+    update_registered(theme, |settings| {
+        *settings = RegisteredTheme { mode: Some(mode), accent, colors, metrics, high_contrast };
+    });
+}
+
+/// Register the mode of a custom theme
+///
+/// Themes created through [`crate::theme::ModernTheme`] are registered
+/// automatically; call this for your own `Theme::custom` themes when the
+/// luminance-based detection does not pick the mode you want.
+///
+/// Settings belong to the theme value and its clones, not to its name, and
+/// are forgotten once every clone is dropped. Built-in iced themes are ignored.
+pub fn register_mode(theme: &iced::Theme, mode: Mode) {
+    update_registered(theme, |settings| settings.mode = Some(mode));
+}
+
+/// Register the accent of a custom theme
+///
+/// Passing `None` restores the default system blue accent.
+pub fn register_accent(theme: &iced::Theme, accent: Option<Accent>) {
+    update_registered(theme, |settings| settings.accent = accent);
+}
+
+/// Register a custom palette for a custom theme
+///
+/// Every `Modern::*` style function picks the palette up at render time.
+/// Passing `None` restores the default palette of the theme's mode.
+pub fn register_colors(theme: &iced::Theme, colors: Option<ThemeColors>) {
+    update_registered(theme, |settings| settings.colors = colors);
+}
+
+/// Register corner radii and control shadow for a custom theme
+///
+/// Passing `None` restores the default [`Metrics`].
+pub fn register_metrics(theme: &iced::Theme, metrics: Option<Metrics>) {
+    update_registered(theme, |settings| settings.metrics = metrics);
+}
+
+/// Register whether a custom theme should use high contrast styling
+///
+/// High contrast themes replace translucent tints with opaque fills and add
+/// visible borders around cards and focused fields.
+pub fn register_high_contrast(theme: &iced::Theme, high_contrast: bool) {
+    update_registered(theme, |settings| settings.high_contrast = high_contrast);
+}
+
+/// Resolve the mode of any iced theme
+///
+/// Registered custom themes use their explicit mode. Every other theme,
+/// including the built-in iced themes, is classified from the luminance of
+/// its palette background.
+pub fn theme_mode(theme: &iced::Theme) -> Mode {
     match theme {
-        iced::Theme::Dark => true,
-        iced::Theme::Custom(custom) => custom.to_string().contains("Dark"),
-        _ => false
+        iced::Theme::Light => Mode::Light,
+        iced::Theme::Dark => Mode::Dark,
+        _ => registered(theme)
+            .and_then(|registered| registered.mode)
+            .unwrap_or_else(|| Mode::from_background(theme.palette().background)),
+    }
+}
+
+/// Resolve the accent of any iced theme
+///
+/// Themes without a registered accent use the system blue.
+pub fn theme_accent(theme: &iced::Theme) -> Accent {
+    registered(theme)
+        .and_then(|registered| registered.accent)
+        .unwrap_or(Accent::SYSTEM)
+}
+
+/// Whether a theme was registered with high contrast styling
+pub fn is_high_contrast(theme: &iced::Theme) -> bool {
+    registered(theme).is_some_and(|registered| registered.high_contrast)
+}
+
+/// Shift the perceptual lightness of a color, keeping its hue
+///
+/// Works in OKLCH, so saturated colors don't drift in hue and light grays
+/// change as visibly as dark ones. Chroma is reduced where the shifted color
+/// would fall outside sRGB. Alpha is preserved.
+pub fn shift_lightness(color: Color, delta: f32) -> Color {
+    let mut oklch = Oklch::from_color(Srgb::new(color.r, color.g, color.b));
+    oklch.l = (oklch.l + delta).clamp(0.0, 1.0);
+
+    from_oklch(oklch, color.a)
+}
+
+/// Convert an OKLCH color to sRGB, reducing chroma until it fits the gamut
+pub(crate) fn from_oklch(mut oklch: Oklch, alpha: f32) -> Color {
+    let mut rgb: Srgb = oklch.into_color();
+    for _ in 0..16 {
+        if rgb.is_within_bounds() {
+            break;
+        }
+        oklch.chroma *= 0.85;
+        rgb = oklch.into_color();
+    }
+    let rgb = rgb.clamp();
+
+    Color { r: rgb.red, g: rgb.green, b: rgb.blue, a: alpha }
+}
+
+/// Mix two colors in OKLab, `factor` of the way from `color` to `other`
+pub fn mix(color: Color, other: Color, factor: f32) -> Color {
+    let from = Oklab::from_color(Srgb::new(color.r, color.g, color.b));
+    let to = Oklab::from_color(Srgb::new(other.r, other.g, other.b));
+    let rgb = Srgb::from_color(from.mix(to, factor)).clamp();
+
+    Color {
+        r: rgb.red,
+        g: rgb.green,
+        b: rgb.blue,
+        a: color.a + (other.a - color.a) * factor,
     }
 }
 
+/// The color of a control in a hovered or pressed state
+///
+/// Darkens by `delta` in light mode and lightens in dark mode, going the
+/// other way for colors already too close to black or white to move.
+pub fn state_color(color: Color, mode: Mode, delta: f32) -> Color {
+    let lightness = Oklch::from_color(Srgb::new(color.r, color.g, color.b)).l;
+    let darken = match mode {
+        Mode::Light => lightness - delta >= 0.0,
+        Mode::Dark => lightness + delta > 1.0,
+    };
+
+    shift_lightness(color, if darken { -delta } else { delta })
+}
+
+/// Helper function to determine if we're in dark mode
+pub fn is_dark_mode(theme: &iced::Theme) -> bool {
+    theme_mode(theme).is_dark()
+}
+
 /// Get the appropriate colors based on theme mode
+///
+/// Themes registered with a custom palette return that palette; every other
+/// theme gets the default colors for its mode and accent.
 pub fn get_theme_colors(theme: &iced::Theme) -> ThemeColors {
-    let is_dark = is_dark_mode(theme);
-    
-    ThemeColors {
-        // Primary colors
-        blue: if is_dark { colors::system::BLUE_DARK } else { colors::system::BLUE },
-        green: if is_dark { colors::system::GREEN_DARK } else { colors::system::GREEN },
-        orange: if is_dark { colors::system::ORANGE_DARK } else { colors::system::ORANGE },
-        red: if is_dark { colors::system::RED_DARK } else { colors::system::RED },
-        
-        // UI colors
-        text: if is_dark { colors::text::PRIMARY_DARK } else { colors::text::PRIMARY_LIGHT },
-        secondary_text: if is_dark { colors::text::SECONDARY_DARK } else { colors::text::SECONDARY_LIGHT },
-        tertiary_text: if is_dark { colors::text::TERTIARY_DARK } else { colors::text::TERTIARY_LIGHT },
-        
-        // Background colors
-        background: if is_dark { colors::fill::BACKGROUND_DARK } else { colors::fill::BACKGROUND_LIGHT },
-        secondary_background: if is_dark { colors::fill::SECONDARY_DARK } else { colors::fill::SECONDARY_LIGHT },
-        tertiary_background: if is_dark { colors::fill::TERTIARY_DARK } else { colors::fill::TERTIARY_LIGHT },
-        
-        // Element colors
-        card_bg: if is_dark { colors::fill::SECONDARY_DARK } else { colors::fill::BACKGROUND_LIGHT },
-        system_bg: if is_dark { colors::gray::GRAY4_DARK } else { colors::gray::GRAY5_LIGHT },
-        inactive_border: if is_dark { colors::gray::GRAY2_DARK } else { colors::gray::GRAY3_LIGHT },
-        
-        // Status colors
-        placeholder: if is_dark { colors::state::PLACEHOLDER_DARK } else { colors::state::PLACEHOLDER_LIGHT },
-        
-        // Input elements
-        input_bg: if is_dark { colors::fill::SECONDARY_DARK } else { colors::fill::SECONDARY_LIGHT },
-        input_border: if is_dark { colors::gray::GRAY3_DARK } else { colors::gray::GRAY4_LIGHT },
-        
-        // Additional colors
-        separator: if is_dark { colors::state::SEPARATOR_DARK } else { colors::state::SEPARATOR_LIGHT },
-        selection: if is_dark { colors::system::BLUE_DARK.scale_alpha(0.3) } else { colors::system::BLUE.scale_alpha(0.3) },
-        link: if is_dark { colors::system::BLUE_DARK } else { colors::system::BLUE },
-        pink: if is_dark { colors::system::PINK_DARK } else { colors::system::PINK },
-        purple: if is_dark { colors::system::PURPLE_DARK } else { colors::system::PURPLE },
-        indigo: if is_dark { colors::system::INDIGO_DARK } else { colors::system::INDIGO },
-        teal: if is_dark { colors::system::TEAL_DARK } else { colors::system::TEAL },
-        mint: if is_dark { colors::system::MINT_DARK } else { colors::system::MINT },
-        yellow: if is_dark { colors::system::YELLOW_DARK } else { colors::system::YELLOW },
-        brown: if is_dark { colors::system::BROWN_DARK } else { colors::system::BROWN },
-    }
-}
\ No newline at end of file
+    registered(theme)
+        .and_then(|registered| registered.colors)
+        .unwrap_or_else(|| ThemeColors::new(theme_mode(theme), theme_accent(theme)))
+}
+
+/// Get the corner radii and control shadow of a theme
+pub fn get_theme_metrics(theme: &iced::Theme) -> Metrics {
+    registered(theme)
+        .and_then(|registered| registered.metrics)
+        .unwrap_or_default()
+}
+
+impl ThemeColors {
+    /// The default light mode palette
+    pub fn light() -> Self {
+        Self::new(Mode::Light, Accent::SYSTEM)
+    }
+
+    /// The default dark mode palette
+    pub fn dark() -> Self {
+        Self::new(Mode::Dark, Accent::SYSTEM)
+    }
+
+    /// The default palette for the given mode and accent
+    pub fn new(mode: Mode, accent: Accent) -> Self {
+        let is_dark = mode.is_dark();
+        let accent = accent.for_mode(mode);
+        
+        ThemeColors {
+            // Primary colors
+            blue: if is_dark { colors::system::BLUE_DARK } else { colors::system::BLUE },
+            green: if is_dark { colors::system::GREEN_DARK } else { colors::system::GREEN },
+            orange: if is_dark { colors::system::ORANGE_DARK } else { colors::system::ORANGE },
+            red: if is_dark { colors::system::RED_DARK } else { colors::system::RED },
+            
+            // UI colors
+            text: if is_dark { colors::text::PRIMARY_DARK } else { colors::text::PRIMARY_LIGHT },
+            secondary_text: if is_dark { colors::text::SECONDARY_DARK } else { colors::text::SECONDARY_LIGHT },
+            tertiary_text: if is_dark { colors::text::TERTIARY_DARK } else { colors::text::TERTIARY_LIGHT },
+            
+            // Background colors
+            background: if is_dark { colors::fill::BACKGROUND_DARK } else { colors::fill::BACKGROUND_LIGHT },
+            secondary_background: if is_dark { colors::fill::SECONDARY_DARK } else { colors::fill::SECONDARY_LIGHT },
+            tertiary_background: if is_dark { colors::fill::TERTIARY_DARK } else { colors::fill::TERTIARY_LIGHT },
+            
+            // Element colors
+            card_bg: if is_dark { colors::fill::SECONDARY_DARK } else { colors::fill::BACKGROUND_LIGHT },
+            sheet_bg: if is_dark { colors::fill::TERTIARY_DARK } else { colors::fill::SECONDARY_LIGHT },
+            group_bg: if is_dark { colors::fill::SECONDARY_DARK } else { colors::fill::SECONDARY_LIGHT },
+            sidebar_bg: if is_dark { Color::from_rgb(0.15, 0.15, 0.16) } else { Color::from_rgb(0.92, 0.92, 0.93) },
+            system_bg: if is_dark { colors::gray::GRAY4_DARK } else { colors::gray::GRAY5_LIGHT },
+            inactive_border: if is_dark { colors::gray::GRAY2_DARK } else { colors::gray::GRAY3_LIGHT },
+            
+            // Status colors
+            placeholder: if is_dark { colors::state::PLACEHOLDER_DARK } else { colors::state::PLACEHOLDER_LIGHT },
+            
+            // Input elements
+            input_bg: if is_dark { colors::fill::SECONDARY_DARK } else { colors::fill::SECONDARY_LIGHT },
+            input_border: if is_dark { colors::gray::GRAY3_DARK } else { colors::gray::GRAY4_LIGHT },
+            
+            // Accent colors
+            accent,
+            focus_ring: accent,
+            
+            // Additional colors
+            separator: if is_dark { colors::state::SEPARATOR_DARK } else { colors::state::SEPARATOR_LIGHT },
+            selection: accent.scale_alpha(0.3),
+            link: accent,
+            gray: if is_dark { colors::gray::GRAY3_DARK } else { colors::gray::GRAY4_LIGHT },
+            pink: if is_dark { colors::system::PINK_DARK } else { colors::system::PINK },
+            purple: if is_dark { colors::system::PURPLE_DARK } else { colors::system::PURPLE },
+            indigo: if is_dark { colors::system::INDIGO_DARK } else { colors::system::INDIGO },
+            teal: if is_dark { colors::system::TEAL_DARK } else { colors::system::TEAL },
+            mint: if is_dark { colors::system::MINT_DARK } else { colors::system::MINT },
+            yellow: if is_dark { colors::system::YELLOW_DARK } else { colors::system::YELLOW },
+            brown: if is_dark { colors::system::BROWN_DARK } else { colors::system::BROWN },
+        }
+    }
+
+    /// The high contrast palette for the given mode
+    ///
+    /// Uses the [`colors::accessibility`] system colors, stronger text and
+    /// border colors, and a more opaque selection.
+    pub fn high_contrast(mode: Mode) -> Self {
+        let is_dark = mode.is_dark();
+        let accent = Accent::new(colors::accessibility::BLUE_LIGHT, colors::accessibility::BLUE_DARK);
+        let border = if is_dark { colors::gray::GRAY1_DARK } else { colors::text::SECONDARY_LIGHT };
+        let defaults = Self::new(mode, accent);
+        
+        ThemeColors {
+            // Accessible system colors
+            blue: accent.for_mode(mode),
+            green: if is_dark { colors::accessibility::GREEN_DARK } else { colors::accessibility::GREEN_LIGHT },
+            orange: if is_dark { colors::accessibility::ORANGE_DARK } else { colors::accessibility::ORANGE_LIGHT },
+            red: if is_dark { colors::accessibility::RED_DARK } else { colors::accessibility::RED_LIGHT },
+            yellow: if is_dark { colors::accessibility::YELLOW_DARK } else { colors::accessibility::YELLOW_LIGHT },
+            
+            // Stronger text
+            secondary_text: if is_dark { Color::from_rgb(0.92, 0.92, 0.96) } else { Color::from_rgb(0.24, 0.24, 0.26) }, // #EBEBF5 / #3C3C43
+            tertiary_text: if is_dark { colors::text::SECONDARY_DARK } else { colors::text::SECONDARY_LIGHT },
+            placeholder: if is_dark { colors::text::SECONDARY_DARK } else { colors::text::SECONDARY_LIGHT },
+            
+            // Stronger borders and separators
+            inactive_border: border,
+            input_border: border,
+            separator: if is_dark { colors::gray::GRAY1_DARK } else { colors::gray::GRAY1_LIGHT },
+            
+            selection: accent.for_mode(mode).scale_alpha(0.45),
+            ..defaults
+        }
+    }
+
+    /// Palette derived from a single seed color
+    ///
+    /// See [`TonalScale::theme_colors`](crate::tonal::TonalScale::theme_colors).
+    pub fn from_seed(mode: Mode, seed: Color) -> Self {
+        crate::tonal::TonalScale::from_seed(seed).theme_colors(mode)
+    }
+
+    /// Start a custom palette from the defaults of the given mode
+    pub fn builder(mode: Mode) -> ThemeColorsBuilder {
+        ThemeColorsBuilder {
+            colors: Self::new(mode, Accent::SYSTEM),
+        }
+    }
+
+    /// Replace the accent along with the focus ring, selection and link colors derived from it
+    pub fn with_accent(self, accent: Color) -> Self {
+        Self {
+            accent,
+            focus_ring: accent,
+            selection: accent.scale_alpha(0.3),
+            link: accent,
+            ..self
+        }
+    }
+}
+
+/// Builder for custom [`ThemeColors`] palettes
+///
+/// Starts from the default palette of a mode and overrides individual roles.
+#[derive(Debug, Clone, Copy)]
+pub struct ThemeColorsBuilder {
+    colors: ThemeColors,
+}
+
+impl ThemeColorsBuilder {
+    /// Set the accent, deriving the focus ring, selection and link colors from it
+    ///
+    /// Call the individual setters afterwards to override the derived colors.
+    pub fn accent(mut self, color: Color) -> Self {
+        self.colors = self.colors.with_accent(color);
+        self
+    }
+
+    /// Set the blue color
+    pub fn blue(mut self, color: Color) -> Self {
+        self.colors.blue = color;
+        self
+    }
+
+    /// Set the green color
+    pub fn green(mut self, color: Color) -> Self {
+        self.colors.green = color;
+        self
+    }
+
+    /// Set the orange color
+    pub fn orange(mut self, color: Color) -> Self {
+        self.colors.orange = color;
+        self
+    }
+
+    /// Set the red color
+    pub fn red(mut self, color: Color) -> Self {
+        self.colors.red = color;
+        self
+    }
+
+    /// Set the primary text color
+    pub fn text(mut self, color: Color) -> Self {
+        self.colors.text = color;
+        self
+    }
+
+    /// Set the secondary text color
+    pub fn secondary_text(mut self, color: Color) -> Self {
+        self.colors.secondary_text = color;
+        self
+    }
+
+    /// Set the tertiary text color
+    pub fn tertiary_text(mut self, color: Color) -> Self {
+        self.colors.tertiary_text = color;
+        self
+    }
+
+    /// Set the primary background color
+    pub fn background(mut self, color: Color) -> Self {
+        self.colors.background = color;
+        self
+    }
+
+    /// Set the secondary background color
+    pub fn secondary_background(mut self, color: Color) -> Self {
+        self.colors.secondary_background = color;
+        self
+    }
+
+    /// Set the tertiary background color
+    pub fn tertiary_background(mut self, color: Color) -> Self {
+        self.colors.tertiary_background = color;
+        self
+    }
+
+    /// Set the card background color
+    pub fn card_bg(mut self, color: Color) -> Self {
+        self.colors.card_bg = color;
+        self
+    }
+
+    /// Set the sheet background color
+    pub fn sheet_bg(mut self, color: Color) -> Self {
+        self.colors.sheet_bg = color;
+        self
+    }
+
+    /// Set the group background color
+    pub fn group_bg(mut self, color: Color) -> Self {
+        self.colors.group_bg = color;
+        self
+    }
+
+    /// Set the sidebar background color
+    pub fn sidebar_bg(mut self, color: Color) -> Self {
+        self.colors.sidebar_bg = color;
+        self
+    }
+
+    /// Set the system control background color
+    pub fn system_bg(mut self, color: Color) -> Self {
+        self.colors.system_bg = color;
+        self
+    }
+
+    /// Set the border of inactive controls color
+    pub fn inactive_border(mut self, color: Color) -> Self {
+        self.colors.inactive_border = color;
+        self
+    }
+
+    /// Set the placeholder text color
+    pub fn placeholder(mut self, color: Color) -> Self {
+        self.colors.placeholder = color;
+        self
+    }
+
+    /// Set the input background color
+    pub fn input_bg(mut self, color: Color) -> Self {
+        self.colors.input_bg = color;
+        self
+    }
+
+    /// Set the input border color
+    pub fn input_border(mut self, color: Color) -> Self {
+        self.colors.input_border = color;
+        self
+    }
+
+    /// Set the focus ring color
+    pub fn focus_ring(mut self, color: Color) -> Self {
+        self.colors.focus_ring = color;
+        self
+    }
+
+    /// Set the separator color
+    pub fn separator(mut self, color: Color) -> Self {
+        self.colors.separator = color;
+        self
+    }
+
+    /// Set the text selection color
+    pub fn selection(mut self, color: Color) -> Self {
+        self.colors.selection = color;
+        self
+    }
+
+    /// Set the link color
+    pub fn link(mut self, color: Color) -> Self {
+        self.colors.link = color;
+        self
+    }
+
+    /// Set the neutral gray control color
+    pub fn gray(mut self, color: Color) -> Self {
+        self.colors.gray = color;
+        self
+    }
+
+    /// Set the pink color
+    pub fn pink(mut self, color: Color) -> Self {
+        self.colors.pink = color;
+        self
+    }
+
+    /// Set the purple color
+    pub fn purple(mut self, color: Color) -> Self {
+        self.colors.purple = color;
+        self
+    }
+
+    /// Set the indigo color
+    pub fn indigo(mut self, color: Color) -> Self {
+        self.colors.indigo = color;
+        self
+    }
+
+    /// Set the teal color
+    pub fn teal(mut self, color: Color) -> Self {
+        self.colors.teal = color;
+        self
+    }
+
+    /// Set the mint color
+    pub fn mint(mut self, color: Color) -> Self {
+        self.colors.mint = color;
+        self
+    }
+
+    /// Set the yellow color
+    pub fn yellow(mut self, color: Color) -> Self {
+        self.colors.yellow = color;
+        self
+    }
+
+    /// Set the brown color
+    pub fn brown(mut self, color: Color) -> Self {
+        self.colors.brown = color;
+        self
+    }
+
+    /// Finish the palette
+    pub fn build(self) -> ThemeColors {
+        self.colors
+    }
+}
+
+impl From<ThemeColors> for ThemeColorsBuilder {
+    fn from(colors: ThemeColors) -> Self {
+        Self { colors }
+    }
+}
@@ -0,0 +1,846 @@
+//! WCAG contrast checking for Modern themes.
+//!
+//! Relative luminance and contrast ratios follow WCAG 2.1 and are computed
+//! with the `palette` crate. [`audit`] renders every Modern style against a
+//! theme, in every status, and reports the foreground/background pairs that
+//! fall below the AA or AAA thresholds. Pairs of disabled controls are
+//! reported as exempt, since WCAG doesn't require contrast for them.
+
+use std::fmt;
+
+use iced::overlay::menu;
+use iced::widget::{
+    button, checkbox, container, pick_list, progress_bar, radio, rule, scrollable, slider, text,
+    text_editor, text_input, toggler,
+};
+#[cfg(feature = "svg")]
+use iced::widget::svg;
+use iced::{Background, Color, Theme};
+use palette::color_difference::Wcag21RelativeContrast;
+use palette::Srgb;
+
+use crate::colors::colors::system;
+use crate::colors::Accent;
+use crate::styles::{style, TintedButtonColor};
+use crate::theme::{Modern, ValidationState};
+
+/// Minimum contrast of normal text at level AA
+pub const AA_NORMAL_TEXT: f32 = 4.5;
+/// Minimum contrast of large text (18pt, or 14pt bold) at level AA
+pub const AA_LARGE_TEXT: f32 = 3.0;
+/// Minimum contrast of normal text at level AAA
+pub const AAA_NORMAL_TEXT: f32 = 7.0;
+/// Minimum contrast of large text at level AAA
+pub const AAA_LARGE_TEXT: f32 = 4.5;
+/// Minimum contrast of icons and other graphical objects (SC 1.4.11)
+pub const NON_TEXT: f32 = 3.0;
+
+/// Relative luminance of a color as defined by WCAG
+///
+/// Alpha is ignored; use [`blend`] first for translucent colors.
+pub fn relative_luminance(color: Color) -> f32 {
+    Srgb::new(color.r, color.g, color.b).relative_luminance().luma
+}
+
+/// Composite a possibly translucent color over an opaque backdrop
+///
+/// Blending happens in linear light, the same way the renderer does it.
+pub fn blend(color: Color, backdrop: Color) -> Color {
+    let top = Srgb::new(color.r, color.g, color.b).into_linear();
+    let bottom = Srgb::new(backdrop.r, backdrop.g, backdrop.b).into_linear();
+    let mixed = Srgb::from_linear(bottom + (top - bottom) * color.a);
+
+    Color::from_rgb(mixed.red, mixed.green, mixed.blue)
+}
+
+/// WCAG contrast ratio between a foreground and a background color
+///
+/// The result ranges from 1.0 to 21.0. A translucent foreground is blended
+/// over the background first; the background itself is treated as opaque.
+pub fn contrast_ratio(foreground: Color, background: Color) -> f32 {
+    let background = Color { a: 1.0, ..background };
+    let foreground = blend(foreground, background);
+
+    Srgb::new(foreground.r, foreground.g, foreground.b)
+        .relative_contrast(Srgb::new(background.r, background.g, background.b))
+}
+
+/// White or black, whichever label reads better on a background
+///
+/// Picks the color with the higher contrast ratio, preferring white on a tie.
+pub fn readable_text(background: Color) -> Color {
+    if contrast_ratio(Color::WHITE, background) >= contrast_ratio(Color::BLACK, background) {
+        Color::WHITE
+    } else {
+        Color::BLACK
+    }
+}
+
+/// WCAG conformance level reached by a contrast ratio
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    /// Below the AA threshold
+    Fail,
+    /// Meets AA but not AAA
+    AA,
+    /// Meets AAA
+    AAA,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Fail => write!(f, "fails AA"),
+            Level::AA => write!(f, "AA"),
+            Level::AAA => write!(f, "AAA"),
+        }
+    }
+}
+
+/// What a foreground color is used for, which decides the thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Usage {
+    /// Body text, labels and input values
+    Text,
+    /// Text of at least 18pt, or 14pt bold
+    LargeText,
+    /// Icons, checkmarks, handles and other graphical objects
+    Graphic,
+}
+
+impl Usage {
+    /// Minimum ratios for AA and AAA
+    ///
+    /// WCAG has no AAA criterion for graphics, so both are 3:1.
+    pub fn thresholds(self) -> (f32, f32) {
+        match self {
+            Usage::Text => (AA_NORMAL_TEXT, AAA_NORMAL_TEXT),
+            Usage::LargeText => (AA_LARGE_TEXT, AAA_LARGE_TEXT),
+            Usage::Graphic => (NON_TEXT, NON_TEXT),
+        }
+    }
+
+    /// Level reached by a contrast ratio for this usage
+    pub fn level(self, ratio: f32) -> Level {
+        let (aa, aaa) = self.thresholds();
+
+        if ratio >= aaa {
+            Level::AAA
+        } else if ratio >= aa {
+            Level::AA
+        } else {
+            Level::Fail
+        }
+    }
+}
+
+/// A foreground/background pair below AAA
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastIssue {
+    /// The `Modern` style function, e.g. `"primary_button"`
+    pub style: &'static str,
+    /// The widget status the style was rendered in, e.g. `"hovered"`
+    pub status: &'static str,
+    /// The part of the widget, e.g. `"label"` or `"placeholder"`
+    pub element: &'static str,
+    /// Foreground as rendered, blended over the background
+    pub foreground: Color,
+    /// Background as rendered, blended over the window background
+    pub background: Color,
+    /// Contrast ratio between the two
+    pub ratio: f32,
+    /// How the foreground is used
+    pub usage: Usage,
+    /// Level the pair reaches
+    pub level: Level,
+    /// Whether the pair belongs to a disabled control, which WCAG exempts
+    /// from contrast requirements (SC 1.4.3)
+    pub exempt: bool,
+}
+
+impl fmt::Display for ContrastIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = |color: Color| {
+            let [r, g, b, _] = color.into_rgba8();
+            format!("#{r:02X}{g:02X}{b:02X}")
+        };
+
+        write!(
+            f,
+            "{} ({}) {}: {:.2}:1 [{}] {} on {}",
+            self.style,
+            self.status,
+            self.element,
+            self.ratio,
+            self.level,
+            hex(self.foreground),
+            hex(self.background),
+        )?;
+
+        if self.exempt {
+            write!(f, " (exempt)")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Result of [`audit`]ing a theme
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditReport {
+    /// Name of the audited theme
+    pub theme: String,
+    /// Number of foreground/background pairs that were checked
+    pub checked: usize,
+    /// Every pair below AAA, in style order, exempt pairs included
+    pub issues: Vec<ContrastIssue>,
+}
+
+impl AuditReport {
+    /// Pairs that fail AA, leaving out exempt ones
+    pub fn failing_aa(&self) -> impl Iterator<Item = &ContrastIssue> {
+        self.failing_aaa().filter(|issue| issue.level == Level::Fail)
+    }
+
+    /// Pairs that fail AAA, including those that also fail AA and leaving
+    /// out exempt ones
+    pub fn failing_aaa(&self) -> impl Iterator<Item = &ContrastIssue> {
+        self.issues.iter().filter(|issue| !issue.exempt)
+    }
+
+    /// Pairs of disabled controls below AAA
+    pub fn exempt(&self) -> impl Iterator<Item = &ContrastIssue> {
+        self.issues.iter().filter(|issue| issue.exempt)
+    }
+
+    /// Whether every checked pair that isn't exempt meets AA
+    pub fn passes_aa(&self) -> bool {
+        self.failing_aa().next().is_none()
+    }
+
+    /// Whether every checked pair that isn't exempt meets AAA
+    pub fn passes_aaa(&self) -> bool {
+        self.failing_aaa().next().is_none()
+    }
+}
+
+impl fmt::Display for AuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Contrast audit of \"{}\": {} of {} pairs below AAA, {} below AA, {} exempt",
+            self.theme,
+            self.failing_aaa().count(),
+            self.checked,
+            self.failing_aa().count(),
+            self.exempt().count(),
+        )?;
+
+        for issue in &self.issues {
+            write!(f, "\n  {issue}")?;
+        }
+
+        Ok(())
+    }
+}
+
+// Disabled statuses are named "disabled…", which marks their pairs exempt
+
+const BUTTON_STATUSES: [(button::Status, &str); 4] = [
+    (button::Status::Active, "active"),
+    (button::Status::Hovered, "hovered"),
+    (button::Status::Pressed, "pressed"),
+    (button::Status::Disabled, "disabled"),
+];
+
+const TEXT_INPUT_STATUSES: [(text_input::Status, &str); 4] = [
+    (text_input::Status::Active, "active"),
+    (text_input::Status::Hovered, "hovered"),
+    (text_input::Status::Focused { is_hovered: false }, "focused"),
+    (text_input::Status::Disabled, "disabled"),
+];
+
+const TEXT_EDITOR_STATUSES: [(text_editor::Status, &str); 4] = [
+    (text_editor::Status::Active, "active"),
+    (text_editor::Status::Hovered, "hovered"),
+    (text_editor::Status::Focused { is_hovered: false }, "focused"),
+    (text_editor::Status::Disabled, "disabled"),
+];
+
+const CHECKBOX_STATUSES: [(checkbox::Status, &str); 6] = [
+    (checkbox::Status::Active { is_checked: false }, "active"),
+    (checkbox::Status::Active { is_checked: true }, "active, checked"),
+    (checkbox::Status::Hovered { is_checked: false }, "hovered"),
+    (checkbox::Status::Hovered { is_checked: true }, "hovered, checked"),
+    (checkbox::Status::Disabled { is_checked: false }, "disabled"),
+    (checkbox::Status::Disabled { is_checked: true }, "disabled, checked"),
+];
+
+const RADIO_STATUSES: [(radio::Status, &str); 4] = [
+    (radio::Status::Active { is_selected: false }, "active"),
+    (radio::Status::Active { is_selected: true }, "active, selected"),
+    (radio::Status::Hovered { is_selected: false }, "hovered"),
+    (radio::Status::Hovered { is_selected: true }, "hovered, selected"),
+];
+
+const PICK_LIST_STATUSES: [(pick_list::Status, &str); 3] = [
+    (pick_list::Status::Active, "active"),
+    (pick_list::Status::Hovered, "hovered"),
+    (pick_list::Status::Opened { is_hovered: false }, "opened"),
+];
+
+const SLIDER_STATUSES: [(slider::Status, &str); 3] = [
+    (slider::Status::Active, "active"),
+    (slider::Status::Hovered, "hovered"),
+    (slider::Status::Dragged, "dragged"),
+];
+
+const TOGGLER_STATUSES: [(toggler::Status, &str); 6] = [
+    (toggler::Status::Active { is_toggled: false }, "active"),
+    (toggler::Status::Active { is_toggled: true }, "active, on"),
+    (toggler::Status::Hovered { is_toggled: false }, "hovered"),
+    (toggler::Status::Hovered { is_toggled: true }, "hovered, on"),
+    (toggler::Status::Disabled { is_toggled: false }, "disabled"),
+    (toggler::Status::Disabled { is_toggled: true }, "disabled, on"),
+];
+
+const SCROLLABLE_STATUSES: [(scrollable::Status, &str); 3] = [
+    (
+        scrollable::Status::Active {
+            is_horizontal_scrollbar_disabled: false,
+            is_vertical_scrollbar_disabled: false,
+        },
+        "active",
+    ),
+    (
+        scrollable::Status::Hovered {
+            is_horizontal_scrollbar_hovered: false,
+            is_vertical_scrollbar_hovered: true,
+            is_horizontal_scrollbar_disabled: false,
+            is_vertical_scrollbar_disabled: false,
+        },
+        "hovered",
+    ),
+    (
+        scrollable::Status::Dragged {
+            is_horizontal_scrollbar_dragged: false,
+            is_vertical_scrollbar_dragged: true,
+            is_horizontal_scrollbar_disabled: false,
+            is_vertical_scrollbar_disabled: false,
+        },
+        "dragged",
+    ),
+];
+
+#[cfg(feature = "svg")]
+const SVG_STATUSES: [(svg::Status, &str); 2] = [
+    (svg::Status::Idle, "idle"),
+    (svg::Status::Hovered, "hovered"),
+];
+
+/// Light system colors, the hardest case for any-color buttons
+const COLORED_BUTTONS: [(Accent, [&str; 3]); 4] = [
+    (
+        Accent::new(system::YELLOW, system::YELLOW_DARK),
+        ["filled_button(yellow)", "tinted_button_with(yellow)", "outlined_button(yellow)"],
+    ),
+    (
+        Accent::new(system::MINT, system::MINT_DARK),
+        ["filled_button(mint)", "tinted_button_with(mint)", "outlined_button(mint)"],
+    ),
+    (
+        Accent::new(system::CYAN, system::CYAN_DARK),
+        ["filled_button(cyan)", "tinted_button_with(cyan)", "outlined_button(cyan)"],
+    ),
+    (
+        Accent::new(system::BROWN, system::BROWN_DARK),
+        ["filled_button(brown)", "tinted_button_with(brown)", "outlined_button(brown)"],
+    ),
+];
+
+/// Audit a `Modern::conditional_*` style in every validation state
+macro_rules! audit_conditional {
+    ($auditor:ident.$check:ident, $style:ident) => {
+        for (state, name) in [
+            (ValidationState::Valid, concat!(stringify!($style), "(valid)")),
+            (ValidationState::Warning, concat!(stringify!($style), "(warning)")),
+            (ValidationState::Error, concat!(stringify!($style), "(error)")),
+        ] {
+            $auditor.$check(name, Modern::$style(state));
+        }
+    };
+}
+
+/// Audit a style taking a [`TintedButtonColor`] in every color
+macro_rules! audit_tinted {
+    ($auditor:ident.$check:ident, $style:ident) => {
+        for (color, name) in [
+            (TintedButtonColor::Blue, concat!(stringify!($style), "(blue)")),
+            (TintedButtonColor::Green, concat!(stringify!($style), "(green)")),
+            (TintedButtonColor::Red, concat!(stringify!($style), "(red)")),
+            (TintedButtonColor::Orange, concat!(stringify!($style), "(orange)")),
+            (TintedButtonColor::Purple, concat!(stringify!($style), "(purple)")),
+            (TintedButtonColor::Teal, concat!(stringify!($style), "(teal)")),
+            (TintedButtonColor::Pink, concat!(stringify!($style), "(pink)")),
+            (TintedButtonColor::Indigo, concat!(stringify!($style), "(indigo)")),
+        ] {
+            $auditor.$check(name, Modern::$style(color));
+        }
+    };
+}
+
+/// Audit `Modern::emphasized_button` for every button role at the given emphases
+///
+/// The filled emphasis is left out; it renders the same as `Modern::button`.
+macro_rules! audit_emphasized_buttons {
+    ($auditor:ident, $($emphasis:ident),+) => {
+        $(
+            for (role, name) in [
+                (style::Button::Primary, concat!("emphasized_button(primary, ", stringify!($emphasis), ")")),
+                (style::Button::Secondary, concat!("emphasized_button(secondary, ", stringify!($emphasis), ")")),
+                (style::Button::Success, concat!("emphasized_button(success, ", stringify!($emphasis), ")")),
+                (style::Button::Warning, concat!("emphasized_button(warning, ", stringify!($emphasis), ")")),
+                (style::Button::Danger, concat!("emphasized_button(danger, ", stringify!($emphasis), ")")),
+                (style::Button::Link, concat!("emphasized_button(link, ", stringify!($emphasis), ")")),
+                (style::Button::System, concat!("emphasized_button(system, ", stringify!($emphasis), ")")),
+                (style::Button::Plain, concat!("emphasized_button(plain, ", stringify!($emphasis), ")")),
+            ] {
+                $auditor.button(name, Modern::emphasized_button(role, style::Emphasis::$emphasis));
+            }
+        )+
+    };
+}
+
+const TINTED_BUTTONS: [(TintedButtonColor, &str); 8] = [
+    (TintedButtonColor::Blue, "blue_tinted_button"),
+    (TintedButtonColor::Green, "green_tinted_button"),
+    (TintedButtonColor::Red, "red_tinted_button"),
+    (TintedButtonColor::Orange, "orange_tinted_button"),
+    (TintedButtonColor::Purple, "purple_tinted_button"),
+    (TintedButtonColor::Teal, "teal_tinted_button"),
+    (TintedButtonColor::Pink, "pink_tinted_button"),
+    (TintedButtonColor::Indigo, "indigo_tinted_button"),
+];
+
+/// Check every Modern style against a theme
+///
+/// Each style is rendered in all of its statuses and every visible
+/// foreground is compared with the background it is drawn on. Translucent
+/// backgrounds are blended over the theme's window background; styles
+/// without a text color are checked with the theme's text color. Pairs of
+/// disabled statuses are still reported, marked [`ContrastIssue::exempt`].
+pub fn audit(theme: &Theme) -> AuditReport {
+    let mut auditor = Auditor::new(theme);
+
+    // Buttons
+    auditor.button("primary_button", Modern::primary_button());
+    auditor.button("secondary_button", Modern::secondary_button());
+    auditor.button("success_button", Modern::success_button());
+    auditor.button("warning_button", Modern::warning_button());
+    auditor.button("danger_button", Modern::danger_button());
+    auditor.button("link_button", Modern::link_button());
+    auditor.button("system_button", Modern::system_button());
+    auditor.button("plain_button", Modern::plain_button());
+    auditor.button("teal_button", Modern::teal_button());
+    auditor.button("indigo_button", Modern::indigo_button());
+    auditor.button("purple_button", Modern::purple_button());
+    auditor.button("pink_button", Modern::pink_button());
+    auditor.button("gray_button", Modern::gray_button());
+    for (color, name) in TINTED_BUTTONS {
+        auditor.button(name, Modern::tinted_button(color));
+    }
+    for (color, [filled, tinted, outlined]) in COLORED_BUTTONS {
+        auditor.button(filled, Modern::filled_button(color));
+        auditor.button(tinted, Modern::tinted_button_with(color));
+        auditor.button(outlined, Modern::outlined_button(color));
+    }
+    audit_emphasized_buttons!(auditor, Tinted, Outlined, Ghost);
+
+    // Containers
+    auditor.container("transparent_container", Modern::container(style::Container::Transparent));
+    auditor.container("card_container", Modern::card_container());
+    auditor.container("sheet_container", Modern::sheet_container());
+    auditor.container("group_container", Modern::group_container());
+    auditor.container("sidebar_container", Modern::sidebar_container());
+    auditor.container("separated_container", Modern::separated_container());
+    auditor.container("accent_container", Modern::accent_container());
+    auditor.container("toolbar_container", Modern::toolbar_container());
+    auditor.container("floating_container", Modern::floating_container());
+    auditor.container("danger_tooltip_container", Modern::danger_tooltip_container());
+    auditor.container("warning_tooltip_container", Modern::warning_tooltip_container());
+    auditor.container("info_tooltip_container", Modern::info_tooltip_container());
+    audit_conditional!(auditor.container, conditional_tooltip_container);
+    auditor.container("pane_title_bar_container", Modern::pane_title_bar_container());
+    auditor.container("pane_body_container", Modern::pane_body_container());
+    auditor.container("focused_pane_body_container", Modern::focused_pane_body_container());
+
+    // Text inputs
+    auditor.text_input("text_input", Modern::text_input());
+    auditor.text_input("search_input", Modern::search_input());
+    auditor.text_input("inline_text_input", Modern::inline_text_input());
+    auditor.text_input("danger_text_input", Modern::danger_text_input());
+    auditor.text_input("warning_text_input", Modern::warning_text_input());
+    auditor.text_input("combo_box", Modern::combo_box());
+    audit_conditional!(auditor.text_input, conditional_text_input);
+
+    // Text editors
+    auditor.text_editor("text_editor", Modern::text_editor());
+    auditor.text_editor("document_text_editor", Modern::document_text_editor());
+    auditor.text_editor("danger_text_editor", Modern::danger_text_editor());
+    auditor.text_editor("warning_text_editor", Modern::warning_text_editor());
+    audit_conditional!(auditor.text_editor, conditional_text_editor);
+
+    // Form controls
+    auditor.checkbox("checkbox", Modern::checkbox());
+    auditor.radio("radio", Modern::radio());
+    auditor.pick_list("pick_list", Modern::pick_list());
+    auditor.pick_list("danger_pick_list", Modern::danger_pick_list());
+    auditor.pick_list("warning_pick_list", Modern::warning_pick_list());
+    audit_conditional!(auditor.pick_list, conditional_pick_list);
+    auditor.menu("pick_list_menu", Modern::pick_list_menu());
+    auditor.slider("slider", Modern::slider());
+    auditor.slider("vertical_slider", Modern::vertical_slider());
+    audit_tinted!(auditor.slider, colored_slider);
+    auditor.toggler("toggler", Modern::toggler());
+    auditor.toggler("accent_toggler", Modern::accent_toggler());
+    audit_tinted!(auditor.toggler, colored_toggler);
+
+    // Indicators
+    auditor.progress_bar("progress_bar", Modern::progress_bar());
+    auditor.progress_bar("success_progress_bar", Modern::success_progress_bar());
+    auditor.progress_bar("warning_progress_bar", Modern::warning_progress_bar());
+    auditor.progress_bar("danger_progress_bar", Modern::danger_progress_bar());
+    audit_conditional!(auditor.progress_bar, conditional_progress_bar);
+    auditor.scrollable("scrollable", Modern::scrollable());
+    auditor.scrollable("sidebar_scrollable", Modern::sidebar_scrollable());
+    auditor.scrollable("card_scrollable", Modern::card_scrollable());
+    auditor.rule("rule", Modern::rule());
+    auditor.rule("inset_rule", Modern::inset_rule());
+    auditor.rule("thick_rule", Modern::thick_rule());
+
+    // Icons
+    #[cfg(feature = "svg")]
+    {
+        auditor.svg("svg", Modern::svg());
+        auditor.svg("secondary_svg", Modern::secondary_svg());
+        auditor.svg("tertiary_svg", Modern::tertiary_svg());
+        auditor.svg("accent_svg", Modern::accent_svg());
+        audit_tinted!(auditor.svg, tinted_svg);
+        audit_conditional!(auditor.svg, conditional_svg);
+        auditor.svg("plain_button_svg", Modern::plain_button_svg());
+        auditor.svg("link_button_svg", Modern::link_button_svg());
+    }
+
+    // Text
+    auditor.text("primary_text", Modern::primary_text());
+    auditor.text("secondary_text", Modern::secondary_text());
+    auditor.text("tertiary_text", Modern::tertiary_text());
+    auditor.text("link_text", Modern::link_text());
+    auditor.text("red_text", Modern::red_text());
+    auditor.text("blue_text", Modern::blue_text());
+    auditor.text("green_text", Modern::green_text());
+    auditor.text("orange_text", Modern::orange_text());
+    auditor.text("yellow_text", Modern::yellow_text());
+    auditor.text("purple_text", Modern::purple_text());
+    auditor.text("pink_text", Modern::pink_text());
+    auditor.text("teal_text", Modern::teal_text());
+    auditor.text("indigo_text", Modern::indigo_text());
+    auditor.text("mint_text", Modern::mint_text());
+    auditor.text("brown_text", Modern::brown_text());
+    auditor.text("success_text", Modern::success_text());
+    auditor.text("warning_text", Modern::warning_text());
+    auditor.text("error_text", Modern::error_text());
+
+    auditor.finish()
+}
+
+/// The color of a solid background; gradients count as transparent
+fn color_of(background: Background) -> Color {
+    match background {
+        Background::Color(color) => color,
+        _ => Color::TRANSPARENT,
+    }
+}
+
+/// Collects contrast issues while walking the styles of a theme
+struct Auditor<'a> {
+    theme: &'a Theme,
+    /// Window background everything is drawn on
+    surface: Color,
+    /// Text color inherited by widgets that don't set one
+    text: Color,
+    checked: usize,
+    issues: Vec<ContrastIssue>,
+}
+
+impl<'a> Auditor<'a> {
+    fn new(theme: &'a Theme) -> Self {
+        let palette = theme.palette();
+
+        Self {
+            theme,
+            surface: Color { a: 1.0, ..palette.background },
+            text: palette.text,
+            checked: 0,
+            issues: Vec::new(),
+        }
+    }
+
+    /// Resolve a widget background to the opaque color it renders as
+    fn backdrop(&self, background: Option<Background>) -> Color {
+        match background {
+            Some(Background::Color(color)) => blend(color, self.surface),
+            _ => self.surface,
+        }
+    }
+
+    fn check(
+        &mut self,
+        style: &'static str,
+        status: &'static str,
+        element: &'static str,
+        foreground: Color,
+        background: Color,
+        usage: Usage,
+    ) {
+        // Nothing is drawn for fully transparent foregrounds
+        if foreground.a <= 0.0 {
+            return;
+        }
+
+        let foreground = blend(foreground, background);
+        let ratio = contrast_ratio(foreground, background);
+        let level = usage.level(ratio);
+
+        self.checked += 1;
+
+        if level < Level::AAA {
+            self.issues.push(ContrastIssue {
+                style,
+                status,
+                element,
+                foreground,
+                background,
+                ratio,
+                usage,
+                level,
+                exempt: status.starts_with("disabled"),
+            });
+        }
+    }
+
+    fn button(&mut self, name: &'static str, style: impl Fn(&Theme, button::Status) -> button::Style) {
+        for (status, label) in BUTTON_STATUSES {
+            let style = style(self.theme, status);
+            let background = self.backdrop(style.background);
+
+            self.check(name, label, "label", style.text_color, background, Usage::Text);
+        }
+    }
+
+    fn container(&mut self, name: &'static str, style: impl Fn(&Theme) -> container::Style) {
+        let style = style(self.theme);
+        let background = self.backdrop(style.background);
+        let text = style.text_color.unwrap_or(self.text);
+
+        self.check(name, "active", "text", text, background, Usage::Text);
+    }
+
+    fn text_input(
+        &mut self,
+        name: &'static str,
+        style: impl Fn(&Theme, text_input::Status) -> text_input::Style,
+    ) {
+        for (status, label) in TEXT_INPUT_STATUSES {
+            let style = style(self.theme, status);
+            let background = self.backdrop(Some(style.background));
+
+            self.check(name, label, "value", style.value, background, Usage::Text);
+            self.check(name, label, "placeholder", style.placeholder, background, Usage::Text);
+            self.check(name, label, "icon", style.icon, background, Usage::Graphic);
+        }
+    }
+
+    fn checkbox(&mut self, name: &'static str, style: impl Fn(&Theme, checkbox::Status) -> checkbox::Style) {
+        for (status, label) in CHECKBOX_STATUSES {
+            let style = style(self.theme, status);
+            let text = style.text_color.unwrap_or(self.text);
+            let background = self.backdrop(Some(style.background));
+
+            self.check(name, label, "label", text, self.surface, Usage::Text);
+            self.check(name, label, "checkmark", style.icon_color, background, Usage::Graphic);
+        }
+    }
+
+    fn radio(&mut self, name: &'static str, style: impl Fn(&Theme, radio::Status) -> radio::Style) {
+        for (status, label) in RADIO_STATUSES {
+            let style = style(self.theme, status);
+            let text = style.text_color.unwrap_or(self.text);
+            let background = self.backdrop(Some(style.background));
+
+            self.check(name, label, "label", text, self.surface, Usage::Text);
+
+            if matches!(status, radio::Status::Active { is_selected: true } | radio::Status::Hovered { is_selected: true }) {
+                self.check(name, label, "dot", style.dot_color, background, Usage::Graphic);
+            }
+        }
+    }
+
+    fn pick_list(&mut self, name: &'static str, style: impl Fn(&Theme, pick_list::Status) -> pick_list::Style) {
+        for (status, label) in PICK_LIST_STATUSES {
+            let style = style(self.theme, status);
+            let background = self.backdrop(Some(style.background));
+
+            self.check(name, label, "text", style.text_color, background, Usage::Text);
+            self.check(name, label, "placeholder", style.placeholder_color, background, Usage::Text);
+            self.check(name, label, "handle", style.handle_color, background, Usage::Graphic);
+        }
+    }
+
+    fn menu(&mut self, name: &'static str, style: impl Fn(&Theme) -> menu::Style) {
+        let style = style(self.theme);
+        let background = self.backdrop(Some(style.background));
+        let selected = blend(color_of(style.selected_background), background);
+
+        self.check(name, "active", "option", style.text_color, background, Usage::Text);
+        self.check(name, "active", "selected option", style.selected_text_color, selected, Usage::Text);
+    }
+
+    fn text_editor(
+        &mut self,
+        name: &'static str,
+        style: impl Fn(&Theme, text_editor::Status) -> text_editor::Style,
+    ) {
+        for (status, label) in TEXT_EDITOR_STATUSES {
+            let style = style(self.theme, status);
+            let background = self.backdrop(Some(style.background));
+
+            self.check(name, label, "value", style.value, background, Usage::Text);
+            self.check(name, label, "placeholder", style.placeholder, background, Usage::Text);
+        }
+    }
+
+    fn slider(&mut self, name: &'static str, style: impl Fn(&Theme, slider::Status) -> slider::Style) {
+        for (status, label) in SLIDER_STATUSES {
+            let style = style(self.theme, status);
+            let fill = self.backdrop(Some(style.rail.backgrounds.0));
+
+            self.check(name, label, "filled rail", fill, self.surface, Usage::Graphic);
+            self.check(name, label, "handle", color_of(style.handle.background), fill, Usage::Graphic);
+        }
+    }
+
+    fn toggler(&mut self, name: &'static str, style: impl Fn(&Theme, toggler::Status) -> toggler::Style) {
+        for (status, label) in TOGGLER_STATUSES {
+            let style = style(self.theme, status);
+            let text = style.text_color.unwrap_or(self.text);
+            let track = self.backdrop(Some(style.background));
+
+            self.check(name, label, "label", text, self.surface, Usage::Text);
+            self.check(name, label, "track", track, self.surface, Usage::Graphic);
+            self.check(name, label, "knob", color_of(style.foreground), track, Usage::Graphic);
+        }
+    }
+
+    fn progress_bar(&mut self, name: &'static str, style: impl Fn(&Theme) -> progress_bar::Style) {
+        let style = style(self.theme);
+        let track = self.backdrop(Some(style.background));
+
+        self.check(name, "active", "bar", color_of(style.bar), track, Usage::Graphic);
+    }
+
+    fn scrollable(
+        &mut self,
+        name: &'static str,
+        style: impl Fn(&Theme, scrollable::Status) -> scrollable::Style,
+    ) {
+        for (status, label) in SCROLLABLE_STATUSES {
+            let style = style(self.theme, status);
+            let rail = style.vertical_rail;
+            let track = self.backdrop(rail.background);
+
+            self.check(name, label, "scroller", color_of(rail.scroller.background), track, Usage::Graphic);
+        }
+    }
+
+    fn rule(&mut self, name: &'static str, style: impl Fn(&Theme) -> rule::Style) {
+        let style = style(self.theme);
+
+        self.check(name, "active", "line", style.color, self.surface, Usage::Graphic);
+    }
+
+    #[cfg(feature = "svg")]
+    fn svg(&mut self, name: &'static str, style: impl Fn(&Theme, svg::Status) -> svg::Style) {
+        for (status, label) in SVG_STATUSES {
+            // Untinted icons keep their own colors, which can't be checked here
+            if let Some(color) = style(self.theme, status).color {
+                self.check(name, label, "icon", color, self.surface, Usage::Graphic);
+            }
+        }
+    }
+
+    fn text(&mut self, name: &'static str, style: impl Fn(&Theme) -> text::Style) {
+        let style = style(self.theme);
+        let color = style.color.unwrap_or(self.text);
+
+        self.check(name, "active", "text", color, self.surface, Usage::Text);
+    }
+
+    fn finish(self) -> AuditReport {
+        AuditReport {
+            theme: self.theme.to_string(),
+            checked: self.checked,
+            issues: self.issues,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colors::Mode;
+    use crate::theme::ModernTheme;
+
+    #[test]
+    fn black_on_white_is_21_to_1() {
+        let ratio = contrast_ratio(Color::BLACK, Color::WHITE);
+
+        assert!((ratio - 21.0).abs() < 0.01, "{ratio}");
+        assert_eq!(ratio, contrast_ratio(Color::WHITE, Color::BLACK));
+    }
+
+    #[test]
+    fn identical_colors_are_1_to_1() {
+        for color in [Color::BLACK, Color::WHITE, system::YELLOW, system::BROWN_DARK] {
+            assert!((contrast_ratio(color, color) - 1.0).abs() < 0.001, "{color:?}");
+        }
+    }
+
+    #[test]
+    fn readable_text_picks_the_stronger_label() {
+        assert_eq!(readable_text(Color::BLACK), Color::WHITE);
+        assert_eq!(readable_text(Color::WHITE), Color::BLACK);
+        assert_eq!(readable_text(system::YELLOW), Color::BLACK);
+        assert_eq!(readable_text(Color::from_rgb8(0x1C, 0x1C, 0x1E)), Color::WHITE);
+    }
+
+    #[test]
+    fn audit_reports_a_low_contrast_accent() {
+        let theme = ModernTheme::new("Pale Accent", Mode::Light)
+            .with_accent(Color::from_rgb(1.0, 0.95, 0.7))
+            .theme();
+        let report = audit(&theme);
+
+        assert!(!report.passes_aa());
+        assert!(report.failing_aa().any(|issue| issue.style == "link_text"), "{report}");
+    }
+
+    #[test]
+    fn disabled_pairs_are_exempt() {
+        let report = audit(&Modern::light_theme());
+
+        assert!(report.exempt().next().is_some(), "{report}");
+        assert!(report.exempt().all(|issue| issue.status.starts_with("disabled")));
+        assert!(report.failing_aaa().all(|issue| !issue.status.starts_with("disabled")));
+    }
+}
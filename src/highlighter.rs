@@ -0,0 +1,671 @@
+//! Syntax highlighting in Modern system colors.
+//!
+//! [`Highlighter`] is a lightweight, line-based highlighter for iced's
+//! `text_editor`. Token colors come from [`colors::system`] with separate
+//! light and dark mappings, picked with [`is_dark_mode`] every time the
+//! editor is drawn, so code follows the theme without re-highlighting.
+//!
+//! ```ignore
+//! use iced_modern_theme::highlighter::{self, Highlighter, Language};
+//!
+//! text_editor(&content)
+//!     .highlight_with::<Highlighter>(Language::Rust, highlighter::to_format)
+//!     .style(Modern::document_text_editor())
+//! ```
+//!
+//! Read-only snippets can use the same colors through [`spans`]:
+//!
+//! ```ignore
+//! rich_text(highlighter::spans(CONFIG, Language::Toml, &theme)).font(Font::MONOSPACE)
+//! ```
+//!
+//! [`colors::system`]: crate::colors::colors::system
+
+use std::borrow::Borrow;
+use std::ops::Range;
+
+use iced::advanced::text::highlighter::Format;
+use iced::advanced::text::{self, Span};
+use iced::{Color, Font, Theme};
+
+use crate::colors::{colors, is_dark_mode, shift_lightness};
+
+/// A language understood by the [`Highlighter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    /// Rust source code
+    Rust,
+    /// TOML configuration
+    Toml,
+    /// JSON documents
+    Json,
+    /// Shell scripts
+    Shell,
+    /// Text without highlighting
+    #[default]
+    Plain,
+}
+
+impl Language {
+    /// Find a language by name or file extension, e.g. `"rs"` or `"toml"`
+    ///
+    /// Unknown tokens return `None`; use [`Language::Plain`] as a fallback.
+    pub fn from_token(token: &str) -> Option<Self> {
+        match token.to_ascii_lowercase().as_str() {
+            "rs" | "rust" => Some(Language::Rust),
+            "toml" => Some(Language::Toml),
+            "json" => Some(Language::Json),
+            "sh" | "bash" | "zsh" | "shell" => Some(Language::Shell),
+            "txt" | "text" | "plain" => Some(Language::Plain),
+            _ => None,
+        }
+    }
+}
+
+/// A highlighted token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token {
+    /// Language keywords (`fn`, `if`, `return`)
+    Keyword,
+    /// Type names
+    Type,
+    /// Function and macro calls, shell commands
+    Function,
+    /// String and character literals
+    String,
+    /// Numeric literals
+    Number,
+    /// Booleans, `null`, constants and shell variables
+    Constant,
+    /// Comments
+    Comment,
+    /// Attributes and TOML table headers
+    Attribute,
+    /// Keys of TOML and JSON documents, shell assignments
+    Key,
+}
+
+impl Token {
+    /// The color of the token in a theme
+    pub fn color(self, theme: &Theme) -> Color {
+        use colors::system::*;
+
+        if is_dark_mode(theme) {
+            match self {
+                Token::Keyword => BLUE_DARK,
+                Token::Type => MINT_DARK,
+                Token::Function => CYAN_DARK,
+                Token::String => GREEN_DARK,
+                Token::Number => ORANGE_DARK,
+                Token::Constant => PURPLE_DARK,
+                Token::Comment => colors::gray::GRAY1_DARK,
+                Token::Attribute => BROWN_DARK,
+                Token::Key => PINK_DARK,
+            }
+        } else {
+            // The bright system colors are darkened to stay readable on white
+            match self {
+                Token::Keyword => BLUE,
+                Token::Type => INDIGO,
+                Token::Function => shift_lightness(TEAL, -0.25),
+                Token::String => shift_lightness(GREEN, -0.2),
+                Token::Number => shift_lightness(ORANGE, -0.15),
+                Token::Constant => PURPLE,
+                Token::Comment => colors::gray::GRAY1_LIGHT,
+                Token::Attribute => BROWN,
+                Token::Key => PINK,
+            }
+        }
+    }
+}
+
+/// Format a token for `text_editor::highlight_with`
+///
+/// Works for editors themed with `iced::Theme` and with `ModernTheme`.
+pub fn to_format<T: Borrow<Theme>>(token: &Token, theme: &T) -> Format<Font> {
+    Format {
+        color: Some(token.color(theme.borrow())),
+        font: None,
+    }
+}
+
+/// Highlight a whole snippet into spans for `rich_text`
+///
+/// Like [`to_format`], takes an `iced::Theme` or a `ModernTheme`.
+pub fn spans<'a, Link, T: Borrow<Theme>>(
+    source: &'a str,
+    language: Language,
+    theme: &T,
+) -> Vec<Span<'a, Link>> {
+    let theme = theme.borrow();
+    let mut spans = Vec::new();
+    let mut state = State::Normal;
+
+    for (index, line) in source.split('\n').enumerate() {
+        if index > 0 {
+            spans.push(Span::new("\n"));
+        }
+
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let (tokens, next) = lex(language, line, state);
+        let mut position = 0;
+
+        for (range, token) in tokens {
+            if range.start > position {
+                spans.push(Span::new(&line[position..range.start]));
+            }
+
+            spans.push(Span::new(&line[range.clone()]).color(token.color(theme)));
+            position = range.end;
+        }
+
+        if position < line.len() {
+            spans.push(Span::new(&line[position..]));
+        }
+
+        state = next;
+    }
+
+    spans
+}
+
+/// A line-based syntax highlighter for [`Language`]s
+///
+/// Block comments and multi-line strings carry over to the following lines.
+#[derive(Debug)]
+pub struct Highlighter {
+    language: Language,
+    /// Lexer state at the start of each line up to the current one
+    states: Vec<State>,
+    current_line: usize,
+}
+
+impl text::Highlighter for Highlighter {
+    type Settings = Language;
+    type Highlight = Token;
+    type Iterator<'a> = std::vec::IntoIter<(Range<usize>, Token)>;
+
+    fn new(language: &Language) -> Self {
+        Self {
+            language: *language,
+            states: vec![State::Normal],
+            current_line: 0,
+        }
+    }
+
+    fn update(&mut self, language: &Language) {
+        *self = Self::new(language);
+    }
+
+    fn change_line(&mut self, line: usize) {
+        let line = line.min(self.states.len() - 1);
+
+        self.states.truncate(line + 1);
+        self.current_line = line;
+    }
+
+    fn highlight_line(&mut self, line: &str) -> Self::Iterator<'_> {
+        let (tokens, next) = lex(self.language, line, self.states[self.current_line]);
+
+        self.states.push(next);
+        self.current_line += 1;
+
+        tokens.into_iter()
+    }
+
+    fn current_line(&self) -> usize {
+        self.current_line
+    }
+}
+
+/// Lexer state carried between lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Normal,
+    BlockComment,
+    String { quote: u8, triple: bool },
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "type", "unsafe", "use",
+    "where", "while", "yield",
+];
+
+const RUST_PRIMITIVES: &[&str] = &[
+    "bool", "char", "str", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+    "i128", "isize", "f32", "f64",
+];
+
+const SHELL_KEYWORDS: &[&str] = &[
+    "if", "then", "else", "elif", "fi", "for", "while", "until", "do", "done", "case", "esac",
+    "in", "function", "return", "local", "export", "select", "time",
+];
+
+/// Highlight one line, starting in `state`
+fn lex(language: Language, line: &str, mut state: State) -> (Vec<(Range<usize>, Token)>, State) {
+    let bytes = line.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    if language == Language::Plain {
+        return (tokens, State::Normal);
+    }
+
+    while i < bytes.len() {
+        match state {
+            State::BlockComment => {
+                let (end, next) = match find(bytes, i, b"*/") {
+                    Some(end) => (end + 2, State::Normal),
+                    None => (bytes.len(), State::BlockComment),
+                };
+
+                tokens.push((i..end, Token::Comment));
+                state = next;
+                i = end;
+            }
+            State::String { quote, triple } => {
+                let (end, closed) = scan_string(bytes, i, quote, triple);
+
+                tokens.push((i..end, Token::String));
+                if closed {
+                    state = State::Normal;
+                }
+                i = end;
+            }
+            State::Normal => {
+                let byte = bytes[i];
+                let rest = &bytes[i..];
+
+                if is_line_comment(language, bytes, i) {
+                    tokens.push((i..bytes.len(), Token::Comment));
+                    i = bytes.len();
+                } else if language == Language::Rust && rest.starts_with(b"/*") {
+                    tokens.push((i..i + 2, Token::Comment));
+                    state = State::BlockComment;
+                    i += 2;
+                } else if is_quote(language, byte) {
+                    let triple = language == Language::Toml && rest.starts_with(&[byte; 3]);
+                    let start = i;
+                    let (end, closed) = scan_string(bytes, i + if triple { 3 } else { 1 }, byte, triple);
+
+                    let token = if language == Language::Json && next_byte(bytes, end) == Some(b':') {
+                        Token::Key
+                    } else {
+                        Token::String
+                    };
+
+                    tokens.push((start..end, token));
+                    if !closed && (triple || matches!(language, Language::Rust | Language::Shell)) {
+                        state = State::String { quote: byte, triple };
+                    }
+                    i = end;
+                } else if language == Language::Rust && byte == b'\'' {
+                    i = rust_char(bytes, i, &mut tokens);
+                } else if byte.is_ascii_digit() {
+                    let end = scan(bytes, i, |byte, next| {
+                        byte.is_ascii_alphanumeric() || byte == b'_' || (byte == b'.' && next.is_some_and(|next| next.is_ascii_digit()))
+                    });
+
+                    tokens.push((i..end, Token::Number));
+                    i = end;
+                } else if is_identifier_start(byte) {
+                    let end = scan(bytes, i, |byte, _| is_identifier_start(byte) || byte.is_ascii_digit());
+                    let (end, token) = classify(language, bytes, i, end);
+
+                    if let Some(token) = token {
+                        tokens.push((i..end, token));
+                    }
+                    i = end;
+                } else if language == Language::Rust && (rest.starts_with(b"#[") || rest.starts_with(b"#![")) {
+                    let end = closing_bracket(bytes, i);
+
+                    tokens.push((i..end, Token::Attribute));
+                    i = end;
+                } else if language == Language::Toml && byte == b'[' && line[..i].trim().is_empty() {
+                    let end = line.rfind(']').map_or(bytes.len(), |end| end + 1);
+
+                    tokens.push((i..end, Token::Attribute));
+                    i = end;
+                } else if language == Language::Shell && byte == b'$' {
+                    let end = if next_byte_raw(bytes, i + 1) == Some(b'{') {
+                        find(bytes, i, b"}").map_or(bytes.len(), |end| end + 1)
+                    } else {
+                        scan(bytes, i + 1, |byte, _| is_identifier_start(byte) || byte.is_ascii_digit())
+                    };
+
+                    tokens.push((i..end, Token::Constant));
+                    i = end;
+                } else {
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    // Only Rust, shell and triple-quoted strings span lines
+    if let State::String { triple: false, .. } = state {
+        if !matches!(language, Language::Rust | Language::Shell) {
+            state = State::Normal;
+        }
+    }
+
+    (tokens, state)
+}
+
+/// Classify an identifier, possibly extending it (e.g. over a macro's `!`)
+fn classify(language: Language, bytes: &[u8], start: usize, end: usize) -> (usize, Option<Token>) {
+    let word = std::str::from_utf8(&bytes[start..end]).unwrap_or_default();
+    let next = next_byte(bytes, end);
+
+    let token = match language {
+        Language::Rust => {
+            if RUST_KEYWORDS.contains(&word) {
+                Some(Token::Keyword)
+            } else if word == "true" || word == "false" {
+                Some(Token::Constant)
+            } else if next_byte_raw(bytes, end) == Some(b'!') && next_byte_raw(bytes, end + 1) != Some(b'=') {
+                return (end + 1, Some(Token::Function));
+            } else if next == Some(b'(') {
+                Some(Token::Function)
+            } else if RUST_PRIMITIVES.contains(&word) {
+                Some(Token::Type)
+            } else if word.starts_with(|c: char| c.is_ascii_uppercase()) {
+                if word.len() > 1 && word.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_') {
+                    Some(Token::Constant)
+                } else {
+                    Some(Token::Type)
+                }
+            } else {
+                None
+            }
+        }
+        Language::Toml => {
+            if matches!(next, Some(b'=' | b'.')) {
+                Some(Token::Key)
+            } else if word == "true" || word == "false" {
+                Some(Token::Constant)
+            } else {
+                None
+            }
+        }
+        Language::Json => matches!(word, "true" | "false" | "null").then_some(Token::Constant),
+        Language::Shell => {
+            let line_start = std::str::from_utf8(&bytes[..start]).unwrap_or_default().trim().is_empty();
+
+            if SHELL_KEYWORDS.contains(&word) {
+                Some(Token::Keyword)
+            } else if next_byte_raw(bytes, end) == Some(b'=') {
+                Some(Token::Key)
+            } else if line_start {
+                Some(Token::Function)
+            } else {
+                None
+            }
+        }
+        Language::Plain => None,
+    };
+
+    (end, token)
+}
+
+/// Highlight a Rust character literal, skipping lifetimes
+fn rust_char(bytes: &[u8], start: usize, tokens: &mut Vec<(Range<usize>, Token)>) -> usize {
+    let end = if next_byte_raw(bytes, start + 1) == Some(b'\\') {
+        // Skip the escaped character, which may be a quote itself
+        bytes
+            .get(start + 3..)
+            .and_then(|rest| rest.iter().take(10).position(|&byte| byte == b'\''))
+            .map(|offset| start + 3 + offset + 1)
+    } else {
+        // The literal may hold a multi-byte character
+        std::str::from_utf8(&bytes[start + 1..])
+            .ok()
+            .and_then(|rest| rest.chars().next())
+            .map(|c| start + 1 + c.len_utf8())
+            .filter(|&close| next_byte_raw(bytes, close) == Some(b'\''))
+            .map(|close| close + 1)
+    };
+
+    match end {
+        Some(end) => {
+            tokens.push((start..end, Token::String));
+            end
+        }
+        None => start + 1,
+    }
+}
+
+fn is_line_comment(language: Language, bytes: &[u8], i: usize) -> bool {
+    match language {
+        Language::Rust => bytes[i..].starts_with(b"//"),
+        Language::Toml => bytes[i] == b'#',
+        // `#` only starts a comment at the start of a word
+        Language::Shell => bytes[i] == b'#' && (i == 0 || bytes[i - 1].is_ascii_whitespace()),
+        Language::Json | Language::Plain => false,
+    }
+}
+
+fn is_quote(language: Language, byte: u8) -> bool {
+    match language {
+        Language::Rust | Language::Json => byte == b'"',
+        Language::Toml | Language::Shell => byte == b'"' || byte == b'\'',
+        Language::Plain => false,
+    }
+}
+
+fn is_identifier_start(byte: u8) -> bool {
+    byte.is_ascii_alphabetic() || byte == b'_' || !byte.is_ascii()
+}
+
+/// Scan a string body from `start`, returning its end and whether it closed
+fn scan_string(bytes: &[u8], start: usize, quote: u8, triple: bool) -> (usize, bool) {
+    let mut i = start;
+
+    while i < bytes.len() {
+        if bytes[i] == b'\\' && quote == b'"' {
+            i += 2;
+        } else if triple && bytes[i..].starts_with(&[quote; 3]) {
+            return (i + 3, true);
+        } else if !triple && bytes[i] == quote {
+            return (i + 1, true);
+        } else {
+            i += 1;
+        }
+    }
+
+    (bytes.len(), false)
+}
+
+/// Advance while `accept(byte, next_byte)` holds
+fn scan(bytes: &[u8], start: usize, accept: impl Fn(u8, Option<u8>) -> bool) -> usize {
+    let mut i = start;
+
+    while i < bytes.len() && accept(bytes[i], bytes.get(i + 1).copied()) {
+        i += 1;
+    }
+
+    i
+}
+
+/// End of an attribute, after its matching `]`
+fn closing_bracket(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0;
+
+    for (offset, &byte) in bytes[start..].iter().enumerate() {
+        match byte {
+            b'[' => depth += 1,
+            b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return start + offset + 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    bytes.len()
+}
+
+fn find(bytes: &[u8], start: usize, needle: &[u8]) -> Option<usize> {
+    bytes
+        .get(start..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|offset| start + offset)
+}
+
+/// The byte at `i`, if any
+fn next_byte_raw(bytes: &[u8], i: usize) -> Option<u8> {
+    bytes.get(i).copied()
+}
+
+/// The first non-whitespace byte from `i`
+fn next_byte(bytes: &[u8], i: usize) -> Option<u8> {
+    bytes.get(i..)?.iter().copied().find(|byte| !byte.is_ascii_whitespace())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::advanced::text::Highlighter as _;
+
+    /// Highlight lines in order, as the editor does, returning each token's text
+    fn highlight<'a>(highlighter: &mut Highlighter, lines: &[&'a str]) -> Vec<Vec<(&'a str, Token)>> {
+        lines
+            .iter()
+            .map(|line| {
+                highlighter
+                    .highlight_line(line)
+                    .map(|(range, token)| (&line[range], token))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn highlight_all<'a>(language: Language, lines: &[&'a str]) -> Vec<Vec<(&'a str, Token)>> {
+        highlight(&mut Highlighter::new(&language), lines)
+    }
+
+    #[test]
+    fn block_comments_continue_across_lines() {
+        let lines = ["let a = 1; /* start", "still a comment", "end */ let b = 2;"];
+        let tokens = highlight_all(Language::Rust, &lines);
+
+        assert_eq!(tokens[0][2], ("/*", Token::Comment));
+        assert_eq!(tokens[0][3], (" start", Token::Comment));
+        assert_eq!(tokens[1], [("still a comment", Token::Comment)]);
+        assert_eq!(tokens[2][0], ("end */", Token::Comment));
+        assert_eq!(tokens[2][1], ("let", Token::Keyword));
+    }
+
+    #[test]
+    fn change_line_resumes_from_the_stored_state() {
+        let lines = ["let s = \"first", "second\";", "let t = 1;"];
+        let mut highlighter = Highlighter::new(&Language::Rust);
+        let first = highlight(&mut highlighter, &lines);
+
+        assert_eq!(first[1], [("second\"", Token::String)]);
+        assert_eq!(first[2][0], ("let", Token::Keyword));
+
+        // Re-highlighting from the middle gives the same result
+        highlighter.change_line(1);
+        assert_eq!(highlighter.current_line(), 1);
+        assert_eq!(highlight(&mut highlighter, &lines[1..]), first[1..]);
+
+        // Closing the string on the first line ends it there
+        highlighter.change_line(0);
+        let edited = highlight(&mut highlighter, &["let s = \"first\";", "second", "let t = 1;"]);
+
+        assert_eq!(edited[0][1], ("\"first\"", Token::String));
+        assert!(edited[1].is_empty());
+    }
+
+    #[test]
+    fn escaped_quotes_stay_inside_strings() {
+        let rust = highlight_all(Language::Rust, &[r#"say("a \"quoted\" word", '\'');"#]);
+
+        assert_eq!(rust[0][1], (r#""a \"quoted\" word""#, Token::String));
+        assert_eq!(rust[0][2], (r"'\''", Token::String));
+
+        let json = highlight_all(Language::Json, &[r#"{"a\"b": "c\\"}"#]);
+
+        assert_eq!(json[0], [(r#""a\"b""#, Token::Key), (r#""c\\""#, Token::String)]);
+    }
+
+    #[test]
+    fn non_ascii_text_next_to_tokens_does_not_panic() {
+        let lines = [
+            "let café = \"naïve\"; // ünïcode",
+            "é1 'é' '\\é ä#ö \"\\",
+            "[täble] ключ = 'значение' # 注释",
+            "{\"ключ\": \"日本\", \"é\":1é}",
+            "echo $ñ ${ß} ∂=1 \"\\€",
+            "/* ✓",
+        ];
+
+        for language in [Language::Rust, Language::Toml, Language::Json, Language::Shell, Language::Plain] {
+            let source = lines.join("\n");
+            let spans: Vec<Span<'_>> = spans(&source, language, &Theme::Light);
+            let text: String = spans.iter().map(|span| &*span.text).collect();
+
+            assert_eq!(text, source, "{language:?}");
+        }
+
+        let tokens = highlight_all(Language::Rust, &lines[..1]);
+
+        assert_eq!(tokens[0][1], ("\"naïve\"", Token::String));
+        assert_eq!(tokens[0][2], ("// ünïcode", Token::Comment));
+    }
+
+    #[test]
+    fn lifetimes_are_not_characters() {
+        let tokens = highlight_all(Language::Rust, &["fn first<'a>(s: &'a str) -> char { 'a' }"]);
+        let strings: Vec<_> = tokens[0].iter().filter(|(_, token)| *token == Token::String).collect();
+
+        assert_eq!(strings, [&("'a'", Token::String)]);
+        assert!(tokens[0].contains(&("str", Token::Type)));
+    }
+
+    #[test]
+    fn toml_table_headers_are_attributes() {
+        let tokens = highlight_all(
+            Language::Toml,
+            &["[package]", "  [[bin]] # targets", "name = \"demo\"", "list = [1, 2]"],
+        );
+
+        assert_eq!(tokens[0], [("[package]", Token::Attribute)]);
+        assert_eq!(tokens[1], [("[[bin]]", Token::Attribute), ("# targets", Token::Comment)]);
+        assert_eq!(tokens[2], [("name", Token::Key), ("\"demo\"", Token::String)]);
+        assert_eq!(tokens[3], [("list", Token::Key), ("1", Token::Number), ("2", Token::Number)]);
+    }
+
+    #[test]
+    fn json_keys_are_told_apart_from_values() {
+        let tokens = highlight_all(Language::Json, &[r#"{"name": "value", "tags" : ["a", "b"], "ok": true}"#]);
+
+        assert_eq!(
+            tokens[0],
+            [
+                (r#""name""#, Token::Key),
+                (r#""value""#, Token::String),
+                (r#""tags""#, Token::Key),
+                (r#""a""#, Token::String),
+                (r#""b""#, Token::String),
+                (r#""ok""#, Token::Key),
+                ("true", Token::Constant),
+            ]
+        );
+    }
+
+    #[test]
+    fn spans_color_tokens_for_the_theme() {
+        let spans: Vec<Span<'_>> = spans("let x = 1;", Language::Rust, &Theme::Dark);
+
+        assert_eq!(spans[0].text, "let");
+        assert_eq!(spans[0].color, Some(Token::Keyword.color(&Theme::Dark)));
+        assert_eq!(spans[1].color, None);
+    }
+}
@@ -11,13 +11,25 @@
 //! - Styled components: buttons, text inputs, containers, etc.
 //! - Variants for different button styles (primary, secondary, tinted, etc.)
 //! - Ready-to-use theme that can be applied to any Iced application
+//! - `ModernTheme` as an application theme, styling every widget by default
+//! - WCAG contrast checking and theme audits
+//! - Tonal scales and complete palettes generated from a seed color
+//! - Syntax highlighting for `text_editor` and code snippets in system colors
+//! - Theme files in TOML or JSON (with the `theme-files` feature)
 
+pub mod catalog;
 pub mod colors;
+pub mod contrast;
+pub mod highlighter;
 pub mod styles;
 pub mod theme;
+pub mod tonal;
+#[cfg(feature = "theme-files")]
+pub mod theme_file;
 
 pub use colors::*;
 pub use styles::*;
+pub use tonal::TonalScale;
 
 /// Re-export the Apple struct as the main entry point for the library
-pub use theme::Modern;
\ No newline at end of file
+pub use theme::{Modern, ModernTheme};
\ No newline at end of file
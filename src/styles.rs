@@ -3,10 +3,81 @@
 //! This module provides the style variants and enum definitions
 //! used throughout the Apple theme.
 
+use iced::{Color, Padding, Shadow, Vector};
+
 /// Common constants for element sizing
 pub const CORNER_RADIUS: f32 = 8.0;
 pub const SMALL_CORNER_RADIUS: f32 = 6.0;
 pub const TINY_CORNER_RADIUS: f32 = 4.0;
+pub const CARD_CORNER_RADIUS: f32 = 10.0;
+pub const SHEET_CORNER_RADIUS: f32 = 12.0;
+
+/// Corner radii, control shadow and state deltas used by the Modern styles
+///
+/// The defaults match the sizing constants above; themes can register their
+/// own metrics through `ModernTheme::with_metrics`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Metrics {
+    /// Radius of buttons and search fields
+    pub corner_radius: f32,
+    /// Radius of text inputs and pick lists
+    pub small_corner_radius: f32,
+    /// Radius of checkboxes and menus
+    pub tiny_corner_radius: f32,
+    /// Radius of cards, groups, floating panels and panes
+    pub card_corner_radius: f32,
+    /// Radius of sheets
+    pub sheet_corner_radius: f32,
+    /// Drop shadow of raised controls such as filled buttons
+    pub shadow: Shadow,
+    /// Lightness shifts of hovered and pressed controls
+    pub states: StateDeltas,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            corner_radius: CORNER_RADIUS,
+            small_corner_radius: SMALL_CORNER_RADIUS,
+            tiny_corner_radius: TINY_CORNER_RADIUS,
+            card_corner_radius: CARD_CORNER_RADIUS,
+            sheet_corner_radius: SHEET_CORNER_RADIUS,
+            shadow: Shadow {
+                color: Color { a: 0.1, ..Color::BLACK },
+                offset: Vector::new(0.0, 1.0),
+                blur_radius: 2.0,
+            },
+            states: StateDeltas::default(),
+        }
+    }
+}
+
+/// Perceptual lightness shifts applied to interactive states
+///
+/// Deltas are in OKLCH lightness (0.0 to 1.0), so every color changes by the
+/// same perceived amount. Colors darken in light mode and lighten in dark
+/// mode, see [`state_color`](crate::colors::state_color).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "theme-files",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+pub struct StateDeltas {
+    /// Shift of hovered controls
+    pub hovered: f32,
+    /// Shift of pressed controls
+    pub pressed: f32,
+}
+
+impl Default for StateDeltas {
+    fn default() -> Self {
+        Self {
+            hovered: 0.04,
+            pressed: 0.08,
+        }
+    }
+}
 
 /// Apple-styled component variants
 pub mod style {
@@ -31,6 +102,19 @@ pub mod style {
         Plain,
     }
 
+    /// Emphasis levels a button role can be drawn at
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Emphasis {
+        /// Solid fill in the role color
+        Filled,
+        /// Semi-transparent fill with a colored label
+        Tinted,
+        /// Transparent with a colored border and label
+        Outlined,
+        /// Colored label only; the fill appears on hover
+        Ghost,
+    }
+
     /// Container style variants
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub enum Container {
@@ -46,17 +130,71 @@ pub mod style {
         Sidebar,
     }
 
+    /// Text style variants
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Text {
+        /// Inherits the color of the surrounding widget
+        Default,
+        /// Main content text
+        Primary,
+        /// Supporting information
+        Secondary,
+        /// Hints and disabled labels
+        Tertiary,
+        /// Link-colored text
+        Link,
+        /// Positive status text (green)
+        Success,
+        /// Warning status text (orange)
+        Warning,
+        /// Error status text (red)
+        Error,
+    }
+
+    /// Text input style variants
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum TextInput {
+        /// Standard bordered text input
+        Standard,
+        /// Search field with rounded corners
+        Search,
+        /// Inline input with a bottom border only
+        Inline,
+        /// Input with a validation error (red border)
+        Danger,
+        /// Input with a validation warning (orange border)
+        Warning,
+    }
+
     impl Default for Button {
         fn default() -> Self {
             Self::Primary
         }
     }
 
+    impl Default for Emphasis {
+        fn default() -> Self {
+            Self::Filled
+        }
+    }
+
     impl Default for Container {
         fn default() -> Self {
             Self::Transparent
         }
     }
+
+    impl Default for Text {
+        fn default() -> Self {
+            Self::Default
+        }
+    }
+
+    impl Default for TextInput {
+        fn default() -> Self {
+            Self::Standard
+        }
+    }
 }
 
 /// Colors available for tinted buttons
@@ -73,9 +211,82 @@ pub enum TintedButtonColor {
 }
 
 /// Size variants for buttons
+///
+/// Each size sets the padding, text size, icon size and minimum height of
+/// the buttons built by `Modern::sized_label_button` and
+/// `Modern::sized_icon_button`, and scales the corner radius.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ButtonSize {
+    /// Compact toolbar buttons
+    ExtraSmall,
     Small,
     Medium,
     Large,
+    /// Hero call-to-action buttons
+    ExtraLarge,
+}
+
+impl ButtonSize {
+    /// Padding around the button content
+    pub fn padding(self) -> Padding {
+        let [vertical, horizontal] = match self {
+            ButtonSize::ExtraSmall => [3.0, 8.0],
+            ButtonSize::Small => [5.0, 12.0],
+            ButtonSize::Medium => [7.0, 16.0],
+            ButtonSize::Large => [10.0, 20.0],
+            ButtonSize::ExtraLarge => [12.0, 28.0],
+        };
+
+        Padding::from([vertical, horizontal])
+    }
+
+    /// Size of the label text
+    pub fn text_size(self) -> f32 {
+        match self {
+            ButtonSize::ExtraSmall => 11.0,
+            ButtonSize::Small => 13.0,
+            ButtonSize::Medium => 15.0,
+            ButtonSize::Large => 17.0,
+            ButtonSize::ExtraLarge => 20.0,
+        }
+    }
+
+    /// Width and height of an icon next to the label
+    pub fn icon_size(self) -> f32 {
+        match self {
+            ButtonSize::ExtraSmall => 12.0,
+            ButtonSize::Small => 14.0,
+            ButtonSize::Medium => 16.0,
+            ButtonSize::Large => 20.0,
+            ButtonSize::ExtraLarge => 24.0,
+        }
+    }
+
+    /// Minimum height of the button, padding included
+    pub fn min_height(self) -> f32 {
+        match self {
+            ButtonSize::ExtraSmall => 22.0,
+            ButtonSize::Small => 28.0,
+            ButtonSize::Medium => 34.0,
+            ButtonSize::Large => 44.0,
+            ButtonSize::ExtraLarge => 52.0,
+        }
+    }
+
+    /// Factor applied to the theme's corner radius
+    pub fn radius_scale(self) -> f32 {
+        match self {
+            ButtonSize::ExtraSmall => 0.6,
+            ButtonSize::Small => 0.8,
+            ButtonSize::Medium => 1.0,
+            ButtonSize::Large => 1.2,
+            ButtonSize::ExtraLarge => 1.4,
+        }
+    }
+}
+
+impl Default for ButtonSize {
+    fn default() -> Self {
+        Self::Medium
+    }
 }
\ No newline at end of file
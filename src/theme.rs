@@ -3,29 +3,33 @@
 //! This module provides the main `Modern` struct and implementations
 //! for styling each Iced component with Modern-inspired designs.
 
-use iced::{Border, Color, Shadow, Theme, Background, Vector};
+use iced::alignment::Vertical;
+use iced::{border, Border, Color, Element, Shadow, Theme, Background, Vector};
 use iced::overlay::menu;
+use iced::theme::palette::{self, Extended, Pair};
 
 /// Modern design-inspired text input style implementation
 fn text_input_style(theme: &Theme, status: TextInputStatus) -> text_input::Style {
     let colors = get_theme_colors(theme);
+    let metrics = get_theme_metrics(theme);
     
     let base_style = text_input::Style {
         background: Background::Color(colors.input_bg),
         border: Border {
-            radius: SMALL_CORNER_RADIUS.into(),
+            radius: metrics.small_corner_radius.into(),
             width: 1.0,
             color: colors.input_border,
         },
         icon: colors.text,
         placeholder: colors.placeholder,
         value: colors.text,
-        selection: colors.blue.scale_alpha(0.3),
+        selection: colors.selection,
     };
     
     match status {
         TextInputStatus::Active => base_style,
         TextInputStatus::Hovered => text_input::Style {
+            background: input_hover_background(theme, base_style.background),
             border: Border {
                 color: colors.placeholder,
                 ..base_style.border
@@ -34,7 +38,7 @@ fn text_input_style(theme: &Theme, status: TextInputStatus) -> text_input::Style
         },
         TextInputStatus::Focused  { is_hovered: _ }  => text_input::Style {
             border: Border {
-                color: colors.blue,
+                color: colors.focus_ring,
                 width: 2.0,
                 ..base_style.border
             },
@@ -52,9 +56,36 @@ fn text_input_style(theme: &Theme, status: TextInputStatus) -> text_input::Style
     }
 }
 
+/// Render a text editor with a text input style
+///
+/// Both widgets share the same statuses, so the Modern text editors follow
+/// their text input counterparts exactly.
+fn text_editor_style(
+    input_style: impl Fn(&Theme, TextInputStatus) -> text_input::Style,
+    theme: &Theme,
+    status: text_editor::Status,
+) -> text_editor::Style {
+    let status = match status {
+        text_editor::Status::Active => TextInputStatus::Active,
+        text_editor::Status::Hovered => TextInputStatus::Hovered,
+        text_editor::Status::Focused { is_hovered } => TextInputStatus::Focused { is_hovered },
+        text_editor::Status::Disabled => TextInputStatus::Disabled,
+    };
+    let style = input_style(theme, status);
+    
+    text_editor::Style {
+        background: style.background,
+        border: style.border,
+        placeholder: style.placeholder,
+        value: style.value,
+        selection: style.selection,
+    }
+}
+
 /// Modern design-inspired pick list style implementation
 fn pick_list_style(theme: &Theme, status: pick_list::Status) -> pick_list::Style {
     let colors = get_theme_colors(theme);
+    let metrics = get_theme_metrics(theme);
     
     // Base style
     let base_style = pick_list::Style {
@@ -62,7 +93,7 @@ fn pick_list_style(theme: &Theme, status: pick_list::Status) -> pick_list::Style
         placeholder_color: colors.placeholder,
         background: Background::Color(colors.input_bg),
         border: Border {
-            radius: SMALL_CORNER_RADIUS.into(),
+            radius: metrics.small_corner_radius.into(),
             width: 1.0,
             color: colors.input_border,
         },
@@ -72,6 +103,7 @@ fn pick_list_style(theme: &Theme, status: pick_list::Status) -> pick_list::Style
     match status {
         pick_list::Status::Active => base_style,
         pick_list::Status::Hovered => pick_list::Style {
+            background: input_hover_background(theme, base_style.background),
             border: Border {
                 color: colors.placeholder,
                 ..base_style.border
@@ -80,11 +112,11 @@ fn pick_list_style(theme: &Theme, status: pick_list::Status) -> pick_list::Style
         },
         pick_list::Status::Opened  { is_hovered: _ }  => pick_list::Style {
             border: Border {
-                color: colors.blue,
+                color: colors.accent,
                 width: 1.5,
                 ..base_style.border
             },
-            handle_color: colors.blue,
+            handle_color: colors.accent,
             ..base_style
         },
     }
@@ -96,25 +128,78 @@ fn combo_box_style(theme: &Theme, status: TextInputStatus) -> text_input::Style
     text_input_style(theme, status)
 }
 
+/// Lay out button content for a size and apply the sized style
+fn sized_button_with<'a, Message, T>(
+    content: impl Into<Element<'a, Message, T>>,
+    size: ButtonSize,
+    style: impl Fn(&Theme, ButtonStatus) -> button::Style + 'a,
+) -> Button<'a, Message, T>
+where
+    Message: 'a,
+    T: button::Catalog + 'a,
+    <T as button::Catalog>::Class<'a>: From<button::StyleFn<'a, Theme>>,
+{
+    let padding = size.padding();
+    let style: button::StyleFn<'a, Theme> = Box::new(Modern::sized_button(style, size));
+    
+    // A zero-width strut sets the minimum height; taller content still grows the button
+    let content = Row::new()
+        .push(Space::new().width(0).height(size.min_height() - padding.y()))
+        .push(content)
+        .align_y(Vertical::Center);
+    
+    Button::new(content).padding(padding).class(style)
+}
+
+/// Center an icon in a square sized for a button size
+fn icon_slot<'a, Message, T>(
+    icon: impl Into<Element<'a, Message, T>>,
+    size: ButtonSize,
+) -> Container<'a, Message, T>
+where
+    Message: 'a,
+    T: container::Catalog + 'a,
+{
+    container(icon).center(size.icon_size())
+}
+
+/// Wrap a label in a tooltip bubble with the shared Modern layout
+fn themed_tooltip<'a, Message, T>(
+    content: impl Into<Element<'a, Message, T>>,
+    label: impl text::IntoFragment<'a>,
+    position: tooltip::Position,
+    style: container::StyleFn<'a, Theme>,
+) -> Tooltip<'a, Message, T>
+where
+    Message: 'a,
+    T: container::Catalog + text::Catalog + 'a,
+    <T as container::Catalog>::Class<'a>: From<container::StyleFn<'a, Theme>>,
+{
+    let label = container(text(label).size(12)).max_width(280);
+    
+    Tooltip::new(content, label, position)
+        .gap(6)
+        .padding(8)
+        .delay(Duration::from_millis(500))
+        .class(style)
+}
+
 /// Create a complete Modern-styled theme
-fn create_modern_theme(dark_mode: bool) -> Theme {
-    let name = if dark_mode { "Modern Dark" } else { "Modern Light" };
+fn create_modern_theme(name: &str, mode: Mode, colors: &ThemeColors, metrics: &Metrics) -> Theme {
+    let dark_mode = mode.is_dark();
     
-    // Define the base colors
-    let (background, text) = if dark_mode {
-        (Color::from_rgb(0.11, 0.11, 0.12), Color::WHITE) // #1C1C1E (dark bg)
-    } else {
-        (Color::from_rgb(0.95, 0.95, 0.97), Color::BLACK) // #F2F2F7 (light bg)
-    };
+    // Define the base colors (light windows use the grouped #F2F2F7 background)
+    let background = if dark_mode { colors.background } else { colors.secondary_background };
+    let text = colors.text;
     
-    let primary = if dark_mode { MODERN_BLUE_DARK } else { MODERN_BLUE_LIGHT };
-    let success = if dark_mode { MODERN_GREEN_DARK } else { MODERN_GREEN_LIGHT };
-    let danger = if dark_mode { MODERN_RED_DARK } else { MODERN_RED_LIGHT };
-    let warning = if dark_mode { MODERN_ORANGE_DARK } else { MODERN_ORANGE_LIGHT };
+    let primary = colors.accent;
+    let success = colors.green;
+    let danger = colors.red;
+    let warning = colors.orange;
     
     // Create the Modern theme
-    Theme::custom(
-        String::from(name),
+    Theme::custom_with_fn(
+        name.to_string(),
         iced::theme::Palette {
             background,
             text,
@@ -122,22 +207,88 @@ fn create_modern_theme(dark_mode: bool) -> Theme {
             success,
             danger,
             warning,
-        }
+        },
+        |palette| extended_palette(palette, mode, colors, metrics),
     )
 }
 
+/// Hand-specified extended palette, so widgets without a Modern style
+/// (and iced's own style functions) use the Modern grays and fills
+fn extended_palette(
+    palette: iced::theme::Palette,
+    mode: Mode,
+    colors: &ThemeColors,
+    metrics: &Metrics,
+) -> Extended {
+    let is_dark = mode.is_dark();
+    let base = palette.background;
+    let text = colors.text;
+    let pair = |color: Color, text: Color| Pair { color, text };
+    
+    // Background levels follow the fill and border grays of the palette
+    let weak = if is_dark { colors.secondary_background } else { colors.tertiary_background };
+    let neutral = colors.input_border;
+    let strong = colors.inactive_border;
+    let strongest = contrast::blend(colors.tertiary_text, base);
+    
+    // Filled controls use the same labels and hover shades as Modern buttons
+    let strong_shade = |color: Color| state_color(color, mode, metrics.states.hovered);
+    let weak_tint = |color: Color| mix(color, base, 0.4);
+    let label = contrast::readable_text;
+    
+    Extended {
+        background: palette::Background {
+            base: pair(base, text),
+            weakest: pair(mix(base, weak, 0.35), text),
+            weaker: pair(mix(base, weak, 0.7), text),
+            weak: pair(weak, text),
+            neutral: pair(neutral, text),
+            strong: pair(strong, text),
+            stronger: pair(mix(strong, strongest, 0.5), text),
+            strongest: pair(strongest, text),
+        },
+        primary: palette::Primary {
+            base: pair(colors.accent, label(colors.accent)),
+            weak: pair(weak_tint(colors.accent), text),
+            strong: pair(strong_shade(colors.accent), label(strong_shade(colors.accent))),
+        },
+        secondary: palette::Secondary {
+            base: pair(colors.system_bg, text),
+            weak: pair(weak, text),
+            strong: pair(colors.gray, text),
+        },
+        success: palette::Success {
+            base: pair(colors.green, label(colors.green)),
+            weak: pair(weak_tint(colors.green), text),
+            strong: pair(strong_shade(colors.green), label(strong_shade(colors.green))),
+        },
+        warning: palette::Warning {
+            base: pair(colors.orange, label(colors.orange)),
+            weak: pair(weak_tint(colors.orange), text),
+            strong: pair(strong_shade(colors.orange), label(strong_shade(colors.orange))),
+        },
+        danger: palette::Danger {
+            base: pair(colors.red, label(colors.red)),
+            weak: pair(weak_tint(colors.red), text),
+            strong: pair(strong_shade(colors.red), label(strong_shade(colors.red))),
+        },
+        is_dark,
+    }
+}
+
 /// Modern design-inspired radio button style implementation
 fn radio_style(theme: &Theme, status: radio::Status) -> radio::Style {
     let colors = get_theme_colors(theme);
+    let hover_alpha = if is_high_contrast(theme) { 1.0 } else { 0.5 };
     
     // Base style
     let style = radio::Style {
         background: Background::Color(Color::TRANSPARENT),
-        dot_color: colors.blue,
+        dot_color: colors.accent,
         border_width: 2.0,
         border_color: match status {
-            radio::Status::Active { is_selected } if is_selected => colors.blue,
-            radio::Status::Hovered { is_selected } if is_selected => colors.blue,
+            radio::Status::Active { is_selected } if is_selected => colors.accent,
+            radio::Status::Hovered { is_selected } if is_selected => colors.accent,
             _ => colors.inactive_border,
         },
         text_color: Some(colors.text),
@@ -147,7 +298,7 @@ fn radio_style(theme: &Theme, status: radio::Status) -> radio::Style {
     match status {
         radio::Status::Hovered { is_selected: true } => style,
         radio::Status::Hovered { is_selected: false } => radio::Style {
-            border_color: colors.blue.scale_alpha(0.5),
+            border_color: colors.accent.scale_alpha(hover_alpha),
             ..style
         },
         _ => style,
@@ -157,15 +308,18 @@ fn radio_style(theme: &Theme, status: radio::Status) -> radio::Style {
 /// Modern design-inspired checkbox style implementation
 fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
     let colors = get_theme_colors(theme);
+    let hover_alpha = if is_high_contrast(theme) { 1.0 } else { 0.5 };
+    let metrics = get_theme_metrics(theme);
+    let check_color = contrast::readable_text(colors.accent);
     
     match status {
         checkbox::Status::Active { is_checked } => {
             if is_checked {
                 checkbox::Style {
-                    background: Background::Color(colors.blue),
-                    icon_color: Color::WHITE,
+                    background: Background::Color(colors.accent),
+                    icon_color: check_color,
                     border: Border {
-                        radius: TINY_CORNER_RADIUS.into(),
+                        radius: metrics.tiny_corner_radius.into(),
                         width: 0.0,
                         color: Color::TRANSPARENT,
                     },
@@ -176,7 +330,7 @@ fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
                     background: Background::Color(Color::TRANSPARENT),
                     icon_color: Color::TRANSPARENT,
                     border: Border {
-                        radius: TINY_CORNER_RADIUS.into(),
+                        radius: metrics.tiny_corner_radius.into(),
                         width: 2.0,
                         color: colors.inactive_border,
                     },
@@ -187,10 +341,12 @@ fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
         checkbox::Status::Hovered { is_checked } => {
             if is_checked {
                 checkbox::Style {
-                    background: Background::Color(colors.blue.scale_alpha(0.9)),
-                    icon_color: Color::WHITE,
+                    background: Background::Color(
+                        state_color(colors.accent, theme_mode(theme), metrics.states.hovered),
+                    ),
+                    icon_color: check_color,
                     border: Border {
-                        radius: TINY_CORNER_RADIUS.into(),
+                        radius: metrics.tiny_corner_radius.into(),
                         width: 0.0,
                         color: Color::TRANSPARENT,
                     },
@@ -201,9 +357,9 @@ fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
                     background: Background::Color(Color::TRANSPARENT),
                     icon_color: Color::TRANSPARENT,
                     border: Border {
-                        radius: TINY_CORNER_RADIUS.into(),
+                        radius: metrics.tiny_corner_radius.into(),
                         width: 2.0,
-                        color: colors.blue.scale_alpha(0.5),
+                        color: colors.accent.scale_alpha(hover_alpha),
                     },
                     text_color: Some(colors.text),
                 }
@@ -212,10 +368,10 @@ fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
         checkbox::Status::Disabled { is_checked } => {
             if is_checked {
                 checkbox::Style {
-                    background: Background::Color(colors.blue.scale_alpha(0.5)),
-                    icon_color: Color::WHITE.scale_alpha(0.5),
+                    background: Background::Color(colors.accent.scale_alpha(0.5)),
+                    icon_color: check_color.scale_alpha(0.5),
                     border: Border {
-                        radius: TINY_CORNER_RADIUS.into(),
+                        radius: metrics.tiny_corner_radius.into(),
                         width: 0.0,
                         color: Color::TRANSPARENT,
                     },
@@ -226,7 +382,7 @@ fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
                     background: Background::Color(Color::TRANSPARENT),
                     icon_color: Color::TRANSPARENT,
                     border: Border {
-                        radius: TINY_CORNER_RADIUS.into(),
+                        radius: metrics.tiny_corner_radius.into(),
                         width: 2.0,
                         color: colors.inactive_border.scale_alpha(0.5),
                     },
@@ -237,9 +393,246 @@ fn checkbox_style(theme: &Theme, status: checkbox::Status) -> checkbox::Style {
     }
 }
 
+/// The system color of a tint variant
+fn tint_color(colors: &ThemeColors, color_variant: TintedButtonColor) -> Color {
+    match color_variant {
+        TintedButtonColor::Blue => colors.blue,
+        TintedButtonColor::Green => colors.green,
+        TintedButtonColor::Red => colors.red,
+        TintedButtonColor::Orange => colors.orange,
+        TintedButtonColor::Purple => colors.purple,
+        TintedButtonColor::Teal => colors.teal,
+        TintedButtonColor::Pink => colors.pink,
+        TintedButtonColor::Indigo => colors.indigo,
+    }
+}
+
+/// Modern design-inspired slider style implementation
+fn slider_style(theme: &Theme, fill: Color, status: slider::Status) -> slider::Style {
+    let colors = get_theme_colors(theme);
+    let metrics = get_theme_metrics(theme);
+    let mode = theme_mode(theme);
+    
+    // A hairline edge stands in for the knob's drop shadow
+    let (handle_border_width, handle_border_color) = if is_high_contrast(theme) {
+        (1.0, colors.inactive_border)
+    } else {
+        (0.5, metrics.shadow.color.scale_alpha(2.0))
+    };
+    
+    let (fill, handle) = match status {
+        slider::Status::Active => (fill, Color::WHITE),
+        slider::Status::Hovered => (state_color(fill, mode, metrics.states.hovered), Color::WHITE),
+        slider::Status::Dragged => (
+            state_color(fill, mode, metrics.states.pressed),
+            state_color(Color::WHITE, Mode::Light, metrics.states.hovered),
+        ),
+    };
+    
+    slider::Style {
+        rail: slider::Rail {
+            backgrounds: (Background::Color(fill), Background::Color(colors.gray)),
+            width: 4.0,
+            border: Border {
+                radius: 2.0.into(),
+                width: 0.0,
+                color: Color::TRANSPARENT,
+            },
+        },
+        handle: slider::Handle {
+            shape: slider::HandleShape::Circle { radius: 8.0 },
+            background: Background::Color(handle),
+            border_width: handle_border_width,
+            border_color: handle_border_color,
+        },
+    }
+}
+
+/// Modern design-inspired toggler style implementation
+fn toggler_style(theme: &Theme, on: Color, status: toggler::Status) -> toggler::Style {
+    let colors = get_theme_colors(theme);
+    let metrics = get_theme_metrics(theme);
+    let mode = theme_mode(theme);
+    
+    let (is_toggled, track, alpha) = match status {
+        toggler::Status::Active { is_toggled } => {
+            (is_toggled, if is_toggled { on } else { colors.gray }, 1.0)
+        },
+        toggler::Status::Hovered { is_toggled } => {
+            let track = if is_toggled { on } else { colors.gray };
+            (is_toggled, state_color(track, mode, metrics.states.hovered), 1.0)
+        },
+        toggler::Status::Disabled { is_toggled } => {
+            (is_toggled, if is_toggled { on } else { colors.gray }, 0.5)
+        },
+    };
+    
+    // The off track relies on its fill alone, which high contrast outlines
+    let track_border_width = if is_high_contrast(theme) && !is_toggled { 1.0 } else { 0.0 };
+    
+    toggler::Style {
+        background: Background::Color(track.scale_alpha(alpha)),
+        background_border_width: track_border_width,
+        background_border_color: colors.inactive_border.scale_alpha(alpha),
+        foreground: Background::Color(Color::WHITE.scale_alpha(alpha)),
+        foreground_border_width: 0.5,
+        foreground_border_color: metrics.shadow.color.scale_alpha(2.0 * alpha),
+        text_color: Some(if alpha < 1.0 { colors.tertiary_text } else { colors.text }),
+        border_radius: None,
+        padding_ratio: 0.1,
+    }
+}
+
+/// Modern design-inspired progress bar style implementation
+fn progress_bar_style(theme: &Theme, bar: Color) -> progress_bar::Style {
+    let colors = get_theme_colors(theme);
+    let metrics = get_theme_metrics(theme);
+    
+    // The track blends into light surfaces, so high contrast outlines it
+    let border_width = if is_high_contrast(theme) { 1.0 } else { 0.0 };
+    
+    progress_bar::Style {
+        background: Background::Color(colors.tertiary_background),
+        bar: Background::Color(bar),
+        border: Border {
+            radius: metrics.tiny_corner_radius.into(),
+            width: border_width,
+            color: colors.inactive_border,
+        },
+    }
+}
+
+/// Modern design-inspired scrollable style implementation
+///
+/// Scrollers are translucent text-colored pills that stay dim while the
+/// pointer is elsewhere. Hovering or dragging a scrollbar reveals its track
+/// and widens the scroller from 6 to 8 pixels within [`Modern::scrollbar`].
+/// `surface` is the background the scrollable sits on.
+fn scrollable_style(theme: &Theme, surface: Color, status: scrollable::Status) -> scrollable::Style {
+    let colors = get_theme_colors(theme);
+    let metrics = get_theme_metrics(theme);
+    let high_contrast = is_high_contrast(theme);
+    
+    let (is_scrollable_hovered, horizontal, vertical) = match status {
+        scrollable::Status::Active {
+            is_horizontal_scrollbar_disabled,
+            is_vertical_scrollbar_disabled,
+        } => (
+            false,
+            (false, false, is_horizontal_scrollbar_disabled),
+            (false, false, is_vertical_scrollbar_disabled),
+        ),
+        scrollable::Status::Hovered {
+            is_horizontal_scrollbar_hovered,
+            is_vertical_scrollbar_hovered,
+            is_horizontal_scrollbar_disabled,
+            is_vertical_scrollbar_disabled,
+        } => (
+            true,
+            (is_horizontal_scrollbar_hovered, false, is_horizontal_scrollbar_disabled),
+            (is_vertical_scrollbar_hovered, false, is_vertical_scrollbar_disabled),
+        ),
+        scrollable::Status::Dragged {
+            is_horizontal_scrollbar_dragged,
+            is_vertical_scrollbar_dragged,
+            is_horizontal_scrollbar_disabled,
+            is_vertical_scrollbar_disabled,
+        } => (
+            true,
+            (false, is_horizontal_scrollbar_dragged, is_horizontal_scrollbar_disabled),
+            (false, is_vertical_scrollbar_dragged, is_vertical_scrollbar_disabled),
+        ),
+    };
+    
+    let rail = |(is_hovered, is_dragged, is_disabled): (bool, bool, bool)| {
+        let scroller_alpha = if is_disabled {
+            0.0
+        } else if is_dragged {
+            0.65
+        } else if is_hovered {
+            0.55
+        } else if is_scrollable_hovered || high_contrast {
+            0.4
+        } else {
+            0.25
+        };
+        
+        // The scroller fills the rail; an inset border in the color behind it
+        // thins it at rest and narrows when the rail is hovered or dragged
+        let (track, inset) = if (is_hovered || is_dragged) && !is_disabled {
+            (Some(mix(surface, colors.text, 0.06)), 1.0)
+        } else {
+            (None, 2.0)
+        };
+        
+        scrollable::Rail {
+            background: track.map(Background::Color),
+            border: border::rounded(u32::MAX),
+            scroller: scrollable::Scroller {
+                background: Background::Color(colors.text.scale_alpha(scroller_alpha)),
+                border: border::rounded(u32::MAX)
+                    .width(inset)
+                    .color(track.unwrap_or(surface)),
+            },
+        }
+    };
+    
+    scrollable::Style {
+        container: container::Style::default(),
+        vertical_rail: rail(vertical),
+        horizontal_rail: rail(horizontal),
+        gap: None,
+        auto_scroll: scrollable::AutoScroll {
+            background: Background::Color(colors.card_bg.scale_alpha(0.9)),
+            border: Border {
+                radius: u32::MAX.into(),
+                width: 0.5,
+                color: colors.inactive_border,
+            },
+            shadow: metrics.shadow,
+            icon: colors.secondary_text,
+        },
+    }
+}
+
+/// Modern design-inspired rule style implementation
+fn rule_style(color: Color, fill_mode: rule::FillMode) -> rule::Style {
+    rule::Style {
+        color,
+        radius: 0.0.into(),
+        fill_mode,
+        snap: true,
+    }
+}
+
+/// Tint a symbolic SVG icon with a single color
+#[cfg(feature = "svg")]
+fn svg_tint(color: Color) -> svg::Style {
+    svg::Style { color: Some(color) }
+}
+
+/// Tint an SVG icon, fading it while hovered like text-only buttons
+///
+/// `svg::Status::Hovered` only covers the icon's own bounds.
+#[cfg(feature = "svg")]
+fn svg_hover_tint(theme: &Theme, color: Color, status: svg::Status) -> svg::Style {
+    match status {
+        svg::Status::Idle => svg_tint(color),
+        svg::Status::Hovered => svg_tint(state_fade(color, get_theme_metrics(theme).states.hovered)),
+    }
+}
+
 /// Modern design-inspired container style
 fn container_style(theme: &Theme, class: &style::Container) -> container::Style {
     let colors = get_theme_colors(theme);
+    let metrics = get_theme_metrics(theme);
+    
+    // High contrast themes outline surfaces instead of relying on subtle fills
+    let (edge_width, edge_color) = if is_high_contrast(theme) {
+        (1.0, colors.separator)
+    } else {
+        (0.0, Color::TRANSPARENT)
+    };
     
     match class {
         style::Container::Transparent => container::Style {
@@ -254,9 +647,9 @@ fn container_style(theme: &Theme, class: &style::Container) -> container::Style
                 text_color: Some(colors.text),
                 background: Some(Background::Color(colors.card_bg)),
                 border: Border {
-                    radius: 10.0.into(), // Modern rounded card corners
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
+                    radius: metrics.card_corner_radius.into(), // Modern rounded card corners
+                    width: edge_width,
+                    color: edge_color,
                 },
                 shadow: Shadow {
                     color: Color { a: 0.1, ..Color::BLACK },
@@ -267,19 +660,13 @@ fn container_style(theme: &Theme, class: &style::Container) -> container::Style
         },
         
         style::Container::Sheet => {
-            let sheet_bg = if is_dark_mode(theme) {
-                Color::from_rgb(0.22, 0.22, 0.23) // #383839 (dark mode sheet)
-            } else {
-                Color::from_rgb(0.95, 0.95, 0.97) // #F2F2F7 (light mode sheet)
-            };
-            
             container::Style {
                 text_color: Some(colors.text),
-                background: Some(Background::Color(sheet_bg)),
+                background: Some(Background::Color(colors.sheet_bg)),
                 border: Border {
-                    radius: 12.0.into(), // Modern rounded sheet corners
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
+                    radius: metrics.sheet_corner_radius.into(), // Modern rounded sheet corners
+                    width: edge_width,
+                    color: edge_color,
                 },
                 shadow: Shadow {
                     color: Color { a: 0.2, ..Color::BLACK },
@@ -290,34 +677,22 @@ fn container_style(theme: &Theme, class: &style::Container) -> container::Style
         },
         
         style::Container::Group => {
-            let group_bg = if is_dark_mode(theme) {
-                Color::from_rgb(0.17, 0.17, 0.18) // #2C2C2E (dark mode group)
-            } else {
-                Color::from_rgb(0.95, 0.95, 0.97) // #F2F2F7 (light mode group)
-            };
-            
             container::Style {
                 text_color: Some(colors.text),
-                background: Some(Background::Color(group_bg)),
+                background: Some(Background::Color(colors.group_bg)),
                 border: Border {
-                    radius: 10.0.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
+                    radius: metrics.card_corner_radius.into(),
+                    width: edge_width,
+                    color: edge_color,
                 },
                 shadow: Shadow::default(), // No shadow for groups
             }
         },
         
         style::Container::Sidebar => {
-            let sidebar_bg = if is_dark_mode(theme) {
-                Color::from_rgb(0.15, 0.15, 0.16) // #262628 (dark mode sidebar)
-            } else {
-                Color::from_rgb(0.92, 0.92, 0.93) // #EAEAEE (light mode sidebar)
-            };
-            
             container::Style {
                 text_color: Some(colors.text),
-                background: Some(Background::Color(sidebar_bg)),
+                background: Some(Background::Color(colors.sidebar_bg)),
                 border: Border::default(),
                 shadow: Shadow {
                     color: Color { a: 0.05, ..Color::BLACK },
@@ -329,66 +704,66 @@ fn container_style(theme: &Theme, class: &style::Container) -> container::Style
     }
 }
 
-fn button_hover_style(base_style: button::Style, is_dark: bool) -> button::Style {
-    let adjust_color = |color: Color| -> Color {
-        if is_dark {
-            // Lighten in dark mode
-            Color {
-                r: (color.r + 0.05).min(1.0),
-                g: (color.g + 0.05).min(1.0),
-                b: (color.b + 0.05).min(1.0),
-                a: color.a,
-            }
-        } else {
-            // Darken in light mode
-            Color {
-                r: (color.r - 0.05).max(0.0),
-                g: (color.g - 0.05).max(0.0),
-                b: (color.b - 0.05).max(0.0),
-                a: color.a,
-            }
-        }
-    };
-    
-    if let Some(Background::Color(color)) = base_style.background {
-        button::Style {
-            background: Some(Background::Color(adjust_color(color))),
+/// Fade a foreground drawn without a fill by one of the theme's state deltas
+///
+/// A lightness shift barely shows on black or white text, so text-only
+/// controls fade instead, to 0.8 alpha when hovered with the default deltas.
+fn state_fade(color: Color, delta: f32) -> Color {
+    color.scale_alpha(1.0 - delta * 5.0)
+}
+
+/// Shift a button style by one of the theme's state deltas
+///
+/// Filled buttons shift their background. Transparent ones (links, plain and
+/// outlined buttons) have nothing to shift there, so their label and border
+/// fade instead.
+fn button_state_style(theme: &Theme, base_style: button::Style, delta: f32) -> button::Style {
+    match base_style.background {
+        Some(Background::Color(color)) if color.a > 0.0 => button::Style {
+            background: Some(Background::Color(state_color(color, theme_mode(theme), delta))),
             ..base_style
-        }
-    } else {
-        base_style
+        },
+        _ => button::Style {
+            text_color: state_fade(base_style.text_color, delta),
+            border: Border {
+                color: state_fade(base_style.border.color, delta),
+                ..base_style.border
+            },
+            ..base_style
+        },
     }
 }
 
-fn button_pressed_style(base_style: button::Style, is_dark: bool) -> button::Style {
-    let adjust_color = |color: Color| -> Color {
-        if is_dark {
-            // Lighten more in dark mode
-            Color {
-                r: (color.r + 0.1).min(1.0),
-                g: (color.g + 0.1).min(1.0),
-                b: (color.b + 0.1).min(1.0),
-                a: color.a,
-            }
-        } else {
-            // Darken more in light mode
-            Color {
-                r: (color.r - 0.1).max(0.0),
-                g: (color.g - 0.1).max(0.0),
-                b: (color.b - 0.1).max(0.0),
-                a: color.a,
-            }
-        }
-    };
-    
-    let mut pressed_style = base_style;
-    pressed_style.shadow = Shadow::default(); // Remove shadow when pressed
-    
-    if let Some(Background::Color(color)) = base_style.background {
-        pressed_style.background = Some(Background::Color(adjust_color(color)));
+fn button_hover_style(base_style: button::Style, theme: &Theme) -> button::Style {
+    button_state_style(theme, base_style, get_theme_metrics(theme).states.hovered)
+}
+
+fn button_pressed_style(base_style: button::Style, theme: &Theme) -> button::Style {
+    button::Style {
+        shadow: Shadow::default(), // Remove shadow when pressed
+        ..button_state_style(theme, base_style, get_theme_metrics(theme).states.pressed)
+    }
+}
+
+/// Background of a hovered input, shifted by the theme's hover delta
+fn input_hover_background(theme: &Theme, background: Background) -> Background {
+    match background {
+        Background::Color(color) if color.a > 0.0 => Background::Color(
+            state_color(color, theme_mode(theme), get_theme_metrics(theme).states.hovered),
+        ),
+        _ => background,
+    }
+}
+
+/// Shift a container's background by one of the theme's state deltas
+fn container_state_style(theme: &Theme, base_style: container::Style, delta: f32) -> container::Style {
+    match base_style.background {
+        Some(Background::Color(color)) if color.a > 0.0 => container::Style {
+            background: Some(Background::Color(state_color(color, theme_mode(theme), delta))),
+            ..base_style
+        },
+        _ => base_style,
     }
-    
-    pressed_style
 }
 
 fn button_disabled_style(base_style: button::Style) -> button::Style {
@@ -406,12 +781,299 @@ fn button_disabled_style(base_style: button::Style) -> button::Style {
     }
 }
 
-use iced::widget::{button, text, text_input, container, radio, checkbox, pick_list, combo_box};
+/// Apply the shared hover, pressed and disabled treatment to a button style
+fn button_status_style(base_style: button::Style, theme: &Theme, status: ButtonStatus) -> button::Style {
+    match status {
+        ButtonStatus::Active => base_style,
+        ButtonStatus::Hovered => button_hover_style(base_style, theme),
+        ButtonStatus::Pressed => button_pressed_style(base_style, theme),
+        ButtonStatus::Disabled => button_disabled_style(base_style),
+    }
+}
+
+/// Filled button in any color, with a readable label
+fn filled_button_style(theme: &Theme, color: Color, status: ButtonStatus) -> button::Style {
+    let metrics = get_theme_metrics(theme);
+    
+    let base_style = button::Style {
+        background: Some(Background::Color(color)),
+        text_color: contrast::readable_text(color),
+        border: Border {
+            radius: metrics.corner_radius.into(),
+            width: 0.0,
+            color: Color::TRANSPARENT,
+        },
+        shadow: metrics.shadow,
+    };
+    
+    button_status_style(base_style, theme, status)
+}
+
+/// Tinted button in any color (semi-transparent background, colored label)
+fn tinted_button_style(theme: &Theme, color: Color, status: ButtonStatus) -> button::Style {
+    let metrics = get_theme_metrics(theme);
+    
+    // High contrast themes use an opaque fill with a contrasting label instead
+    let (background, text_color) = if is_high_contrast(theme) {
+        (color, contrast::readable_text(color))
+    } else {
+        (color.scale_alpha(0.2), color)
+    };
+    
+    let base_style = button::Style {
+        background: Some(Background::Color(background)),
+        text_color,
+        border: Border {
+            radius: metrics.corner_radius.into(),
+            width: 0.0,
+            color: Color::TRANSPARENT,
+        },
+        shadow: Shadow {
+            color: metrics.shadow.color.scale_alpha(0.5), // Lighter shadow for tinted
+            ..metrics.shadow
+        },
+    };
+    
+    button_status_style(base_style, theme, status)
+}
+
+/// Outlined button in any color (transparent background, colored border and label)
+fn outlined_button_style(theme: &Theme, color: Color, status: ButtonStatus) -> button::Style {
+    let metrics = get_theme_metrics(theme);
+    
+    let base_style = button::Style {
+        background: Some(Background::Color(Color::TRANSPARENT)),
+        text_color: color,
+        border: Border {
+            radius: metrics.corner_radius.into(),
+            width: if is_high_contrast(theme) { 2.0 } else { 1.0 },
+            color,
+        },
+        shadow: Shadow::default(),
+    };
+    
+    button_status_style(base_style, theme, status)
+}
+
+use iced::widget::{button, text, text_input, container, radio, checkbox, pick_list, combo_box, slider, toggler, progress_bar, scrollable, rule, text_editor, pane_grid, tooltip, Button, Column, Container, Row, Space, Tooltip};
 use iced::widget::button::Status as ButtonStatus;
 use iced::widget::text_input::Status as TextInputStatus;
 
+use std::sync::OnceLock;
+use std::time::Duration;
+
 use crate::colors::*;
+use crate::contrast::{self, AuditReport};
+use crate::tonal::TonalScale;
 use crate::styles::*;
+#[cfg(feature = "svg")]
+use iced::widget::svg;
+#[cfg(feature = "theme-files")]
+use crate::theme_file::{ThemeEvent, ThemeFile, ThemeFileError, ThemeFormat};
+
+/// A named Modern theme with an explicit light or dark [`Mode`]
+///
+/// Style functions resolve the mode of a theme through [`theme_mode`], so a
+/// theme built from a `ModernTheme` keeps its mode regardless of its name.
+///
+/// `ModernTheme` can also be used directly as the theme of an application.
+/// It implements the style catalogs of the iced widgets, so every widget is
+/// Modern-styled by default; see [`crate::catalog`].
+#[derive(Debug, Clone)]
+pub struct ModernTheme {
+    name: String,
+    mode: Mode,
+    accent: Option<Accent>,
+    colors: Option<ThemeColors>,
+    metrics: Option<Metrics>,
+    high_contrast: bool,
+    /// The iced theme, built on first use
+    theme: OnceLock<Theme>,
+}
+
+impl ModernTheme {
+    /// Create a new Modern theme with the given name and mode
+    pub fn new(name: impl Into<String>, mode: Mode) -> Self {
+        Self {
+            name: name.into(),
+            mode,
+            accent: None,
+            colors: None,
+            metrics: None,
+            high_contrast: false,
+            theme: OnceLock::new(),
+        }
+    }
+
+    /// The default light Modern theme
+    pub fn light() -> Self {
+        Self::new("Modern Light", Mode::Light)
+    }
+
+    /// The default dark Modern theme
+    pub fn dark() -> Self {
+        Self::new("Modern Dark", Mode::Dark)
+    }
+
+    /// Use a custom accent color instead of the system blue
+    pub fn with_accent(mut self, accent: impl Into<Accent>) -> Self {
+        self.accent = Some(accent.into());
+        self.theme.take();
+        self
+    }
+
+    /// Use a custom palette instead of the default colors of the mode
+    ///
+    /// An accent set with [`ModernTheme::with_accent`] still overrides the
+    /// accent of the palette.
+    pub fn with_colors(mut self, colors: ThemeColors) -> Self {
+        self.colors = Some(colors);
+        self.theme.take();
+        self
+    }
+
+    /// Use custom corner radii and control shadow
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = Some(metrics);
+        self.theme.take();
+        self
+    }
+
+    /// Enable or disable high contrast styling
+    ///
+    /// Without a custom palette, the theme uses [`ThemeColors::high_contrast`].
+    pub fn with_high_contrast(mut self, high_contrast: bool) -> Self {
+        self.high_contrast = high_contrast;
+        self.theme.take();
+        self
+    }
+
+    /// The name of the theme
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The mode of the theme
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// The custom accent of the theme, if any
+    pub fn accent(&self) -> Option<Accent> {
+        self.accent
+    }
+
+    /// Whether the theme uses high contrast styling
+    pub fn is_high_contrast(&self) -> bool {
+        self.high_contrast
+    }
+
+    /// The palette the style functions resolve for this theme
+    pub fn colors(&self) -> ThemeColors {
+        let colors = match self.colors {
+            Some(colors) => colors,
+            None if self.high_contrast => ThemeColors::high_contrast(self.mode),
+            None => ThemeColors::new(self.mode, Accent::SYSTEM),
+        };
+
+        match self.accent {
+            Some(accent) => colors.with_accent(accent.for_mode(self.mode)),
+            None => colors,
+        }
+    }
+
+    /// The corner radii and control shadow of the theme
+    pub fn metrics(&self) -> Metrics {
+        self.metrics.unwrap_or_default()
+    }
+
+    /// The iced theme, with its mode, accent, palette and metrics registered for the style functions
+    pub fn theme(&self) -> Theme {
+        self.as_theme().clone()
+    }
+
+    /// Borrow the iced theme the style functions are resolved with
+    ///
+    /// The theme is built and registered the first time it is needed, so
+    /// chaining the `with_*` setters only builds it once.
+    pub fn as_theme(&self) -> &Theme {
+        self.theme.get_or_init(|| self.build())
+    }
+
+    /// Build the iced theme and register it for the style functions
+    fn build(&self) -> Theme {
+        let colors = self.colors();
+        let theme = create_modern_theme(&self.name, self.mode, &colors, &self.metrics());
+
+        register_theme(
+            &theme,
+            self.mode,
+            self.accent,
+            (self.colors.is_some() || self.high_contrast).then_some(colors),
+            self.metrics,
+            self.high_contrast,
+        );
+
+        theme
+    }
+}
+
+impl PartialEq for ModernTheme {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.mode == other.mode
+            && self.accent == other.accent
+            && self.colors == other.colors
+            && self.metrics == other.metrics
+            && self.high_contrast == other.high_contrast
+    }
+}
+
+impl Default for ModernTheme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+impl std::borrow::Borrow<Theme> for ModernTheme {
+    fn borrow(&self) -> &Theme {
+        self.as_theme()
+    }
+}
+
+impl iced::theme::Base for ModernTheme {
+    fn default(preference: iced::theme::Mode) -> Self {
+        match preference {
+            iced::theme::Mode::Dark => Self::dark(),
+            _ => Self::light(),
+        }
+    }
+
+    fn mode(&self) -> iced::theme::Mode {
+        match self.mode {
+            Mode::Light => iced::theme::Mode::Light,
+            Mode::Dark => iced::theme::Mode::Dark,
+        }
+    }
+
+    fn base(&self) -> iced::theme::Style {
+        iced::theme::Base::base(self.as_theme())
+    }
+
+    fn palette(&self) -> Option<iced::theme::Palette> {
+        iced::theme::Base::palette(self.as_theme())
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl From<ModernTheme> for Theme {
+    fn from(theme: ModernTheme) -> Self {
+        theme.theme()
+    }
+}
 
 /// Modern theme utilities for styling iced widgets
 pub struct Modern;
@@ -422,6 +1084,23 @@ impl Modern {
         move |theme, status| button_style(theme, &style, status)
     }
 
+    /// Get a button style for a role at an emphasis level
+    ///
+    /// Every role can be filled, tinted, outlined or ghost, e.g. an outlined
+    /// danger button or a ghost warning button. A filled secondary button is
+    /// gray with an accent label; at the other emphases secondary matches
+    /// primary.
+    ///
+    /// ```ignore
+    /// button("Delete").style(Modern::emphasized_button(style::Button::Danger, style::Emphasis::Outlined))
+    /// ```
+    pub fn emphasized_button<'a>(
+        style: style::Button,
+        emphasis: style::Emphasis,
+    ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        move |theme, status| emphasized_button_style(theme, &style, emphasis, status)
+    }
+
     /// Get an Modern-style theme for primary buttons (blue)
     pub fn primary_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
         Self::button(style::Button::Primary)
@@ -507,14 +1186,596 @@ impl Modern {
         pick_list_style
     }
 
+    /// Get an Modern-style theme for the menu of pick lists
+    ///
+    /// `ModernTheme` applies it to every pick list and combo box menu; with
+    /// `iced::Theme`, pass it to `.menu_style()`. The hovered option is
+    /// highlighted in the accent color.
+    pub fn pick_list_menu<'a>() -> impl Fn(&Theme) -> menu::Style + 'a {
+        |theme| {
+            let colors = get_theme_colors(theme);
+            let metrics = get_theme_metrics(theme);
+            
+            let (edge_width, edge_color) = if is_high_contrast(theme) {
+                (1.0, colors.inactive_border)
+            } else {
+                (0.5, colors.input_border)
+            };
+            
+            menu::Style {
+                text_color: colors.text,
+                background: Background::Color(colors.card_bg),
+                border: Border {
+                    radius: metrics.small_corner_radius.into(),
+                    width: edge_width,
+                    color: edge_color,
+                },
+                selected_text_color: contrast::readable_text(colors.accent),
+                selected_background: Background::Color(colors.accent),
+            }
+        }
+    }
+
+    /// Get a pick list style for selections with validation errors
+    pub fn danger_pick_list<'a>() -> impl Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a {
+        |theme, status| {
+            let colors = get_theme_colors(theme);
+            let base_style = pick_list_style(theme, status);
+            
+            pick_list::Style {
+                border: Border {
+                    color: colors.red,
+                    width: 1.0,
+                    ..base_style.border
+                },
+                background: Background::Color(mix(colors.input_bg, colors.red, 0.08)),
+                handle_color: colors.red,
+                ..base_style
+            }
+        }
+    }
+
+    /// Get a pick list style for selections with validation warnings
+    pub fn warning_pick_list<'a>() -> impl Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a {
+        |theme, status| {
+            let colors = get_theme_colors(theme);
+            let base_style = pick_list_style(theme, status);
+            
+            pick_list::Style {
+                border: Border {
+                    color: colors.orange,
+                    width: 1.0,
+                    ..base_style.border
+                },
+                handle_color: colors.orange,
+                ..base_style
+            }
+        }
+    }
+
+    /// Dynamically choose between danger, warning and standard pick list styles
+    pub fn conditional_pick_list<'a>(
+        validation_state: ValidationState
+    ) -> impl Fn(&Theme, pick_list::Status) -> pick_list::Style + 'a {
+        move |theme, status| {
+            match validation_state {
+                ValidationState::Error => (Self::danger_pick_list())(theme, status),
+                ValidationState::Warning => (Self::warning_pick_list())(theme, status),
+                ValidationState::Valid => (Self::pick_list())(theme, status),
+            }
+        }
+    }
+
+    /// Get an Modern-style theme for sliders (accent-filled rail)
+    pub fn slider<'a>() -> impl Fn(&Theme, slider::Status) -> slider::Style + 'a {
+        |theme, status| slider_style(theme, get_theme_colors(theme).accent, status)
+    }
+
+    /// Get an Modern-style theme for vertical sliders
+    pub fn vertical_slider<'a>() -> impl Fn(&Theme, slider::Status) -> slider::Style + 'a {
+        Self::slider()
+    }
+
+    /// Get an Modern-style slider filled with a system color
+    pub fn colored_slider<'a>(color_variant: TintedButtonColor) -> impl Fn(&Theme, slider::Status) -> slider::Style + 'a {
+        move |theme, status| slider_style(theme, tint_color(&get_theme_colors(theme), color_variant), status)
+    }
+
+    /// Get an Modern-style slider that looks disabled
+    ///
+    /// iced sliders have no disabled status; use this style while the slider
+    /// ignores its input.
+    pub fn disabled_slider<'a>() -> impl Fn(&Theme, slider::Status) -> slider::Style + 'a {
+        |theme, _status| {
+            let style = slider_style(theme, get_theme_colors(theme).accent, slider::Status::Active);
+            let fade = |background: Background| match background {
+                Background::Color(color) => Background::Color(color.scale_alpha(0.5)),
+                _ => background,
+            };
+            
+            slider::Style {
+                rail: slider::Rail {
+                    backgrounds: (fade(style.rail.backgrounds.0), fade(style.rail.backgrounds.1)),
+                    ..style.rail
+                },
+                handle: slider::Handle {
+                    background: fade(style.handle.background),
+                    border_color: style.handle.border_color.scale_alpha(0.5),
+                    ..style.handle
+                },
+            }
+        }
+    }
+
+    /// Get an Modern-style theme for togglers (green track when on)
+    pub fn toggler<'a>() -> impl Fn(&Theme, toggler::Status) -> toggler::Style + 'a {
+        |theme, status| toggler_style(theme, get_theme_colors(theme).green, status)
+    }
+
+    /// Get an Modern-style toggler with an accent track when on
+    pub fn accent_toggler<'a>() -> impl Fn(&Theme, toggler::Status) -> toggler::Style + 'a {
+        |theme, status| toggler_style(theme, get_theme_colors(theme).accent, status)
+    }
+
+    /// Get an Modern-style toggler with a system color track when on
+    pub fn colored_toggler<'a>(color_variant: TintedButtonColor) -> impl Fn(&Theme, toggler::Status) -> toggler::Style + 'a {
+        move |theme, status| toggler_style(theme, tint_color(&get_theme_colors(theme), color_variant), status)
+    }
+
+    /// Get an Modern-style theme for progress bars (accent bar)
+    ///
+    /// Reads best on thin bars; pair it with `.girth(6)` or less.
+    pub fn progress_bar<'a>() -> impl Fn(&Theme) -> progress_bar::Style + 'a {
+        |theme| progress_bar_style(theme, get_theme_colors(theme).accent)
+    }
+
+    /// Get a progress bar style for completed or healthy progress
+    pub fn success_progress_bar<'a>() -> impl Fn(&Theme) -> progress_bar::Style + 'a {
+        |theme| progress_bar_style(theme, get_theme_colors(theme).green)
+    }
+
+    /// Get a progress bar style for stalled or slow progress
+    pub fn warning_progress_bar<'a>() -> impl Fn(&Theme) -> progress_bar::Style + 'a {
+        |theme| progress_bar_style(theme, get_theme_colors(theme).orange)
+    }
+
+    /// Get a progress bar style for failed progress
+    pub fn danger_progress_bar<'a>() -> impl Fn(&Theme) -> progress_bar::Style + 'a {
+        |theme| progress_bar_style(theme, get_theme_colors(theme).red)
+    }
+
+    /// Dynamically choose between success, warning and danger progress bar styles
+    pub fn conditional_progress_bar<'a>(
+        validation_state: ValidationState
+    ) -> impl Fn(&Theme) -> progress_bar::Style + 'a {
+        move |theme| {
+            match validation_state {
+                ValidationState::Error => (Self::danger_progress_bar())(theme),
+                ValidationState::Warning => (Self::warning_progress_bar())(theme),
+                ValidationState::Valid => (Self::success_progress_bar())(theme),
+            }
+        }
+    }
+
+    /// Get an Modern-style theme for scrollables with thin overlay scrollers
+    pub fn scrollable<'a>() -> impl Fn(&Theme, scrollable::Status) -> scrollable::Style + 'a {
+        |theme, status| scrollable_style(theme, get_theme_colors(theme).background, status)
+    }
+
+    /// Get a scrollable style for content inside a `sidebar_container`
+    pub fn sidebar_scrollable<'a>() -> impl Fn(&Theme, scrollable::Status) -> scrollable::Style + 'a {
+        |theme, status| scrollable_style(theme, get_theme_colors(theme).sidebar_bg, status)
+    }
+
+    /// Get a scrollable style for content inside a `card_container`
+    pub fn card_scrollable<'a>() -> impl Fn(&Theme, scrollable::Status) -> scrollable::Style + 'a {
+        |theme, status| scrollable_style(theme, get_theme_colors(theme).card_bg, status)
+    }
+
+    /// Get a thin scrollbar for the Modern scrollable styles
+    ///
+    /// The scroller spans the whole 10 pixel rail, so the full width stays
+    /// grabbable, and the styles inset it to look thinner at rest. The rail
+    /// sits beside the content rather than over it, since the inset is drawn
+    /// in the surface color. Use it in the scrollable's direction:
+    ///
+    /// ```ignore
+    /// scrollable(content)
+    ///     .direction(scrollable::Direction::Vertical(Modern::scrollbar()))
+    ///     .style(Modern::scrollable())
+    /// ```
+    pub fn scrollbar() -> scrollable::Scrollbar {
+        scrollable::Scrollbar::new()
+            .width(10)
+            .scroller_width(10)
+            .margin(2)
+            .spacing(0)
+    }
+
+    /// Get an Modern-style theme for rules (full-width hairline)
+    pub fn rule<'a>() -> impl Fn(&Theme) -> rule::Style + 'a {
+        |theme| rule_style(get_theme_colors(theme).separator, rule::FillMode::Full)
+    }
+
+    /// Get a hairline rule inset from the leading edge, as between list rows
+    pub fn inset_rule<'a>() -> impl Fn(&Theme) -> rule::Style + 'a {
+        |theme| rule_style(get_theme_colors(theme).separator, rule::FillMode::AsymmetricPadding(16, 0))
+    }
+
+    /// Get a section divider rule
+    ///
+    /// Uses the opaque separator color so thicker rules, e.g.
+    /// `rule::horizontal(4)`, stay solid where they cross other content.
+    pub fn thick_rule<'a>() -> impl Fn(&Theme) -> rule::Style + 'a {
+        |theme| {
+            let color = if is_high_contrast(theme) {
+                get_theme_colors(theme).inactive_border
+            } else if is_dark_mode(theme) {
+                colors::state::OPAQUE_SEPARATOR_DARK
+            } else {
+                colors::state::OPAQUE_SEPARATOR_LIGHT
+            };
+            
+            rule::Style {
+                radius: 2.0.into(),
+                ..rule_style(color, rule::FillMode::Full)
+            }
+        }
+    }
+
+    /// Stack children in a column with an inset hairline between each pair
+    ///
+    /// Wrap the result in a `group_container` for grouped list rows:
+    ///
+    /// ```ignore
+    /// container(Modern::separated(rows)).style(Modern::group_container())
+    /// ```
+    pub fn separated<'a, Message, T>(
+        children: impl IntoIterator<Item = Element<'a, Message, T>>,
+    ) -> Column<'a, Message, T>
+    where
+        Message: 'a,
+        T: rule::Catalog + 'a,
+        <T as rule::Catalog>::Class<'a>: From<rule::StyleFn<'a, Theme>>,
+    {
+        let mut column = Column::new();
+        
+        for (index, child) in children.into_iter().enumerate() {
+            if index > 0 {
+                let style: rule::StyleFn<'a, Theme> = Box::new(Self::inset_rule());
+                column = column.push(rule::horizontal(1).class(style));
+            }
+            
+            column = column.push(child);
+        }
+        
+        column
+    }
+
+    /// Get an Modern-style theme for multi-line text editors
+    pub fn text_editor<'a>() -> impl Fn(&Theme, text_editor::Status) -> text_editor::Style + 'a {
+        |theme, status| text_editor_style(text_input_style, theme, status)
+    }
+
+    /// Get a borderless text editor style for full-page documents
+    pub fn document_text_editor<'a>() -> impl Fn(&Theme, text_editor::Status) -> text_editor::Style + 'a {
+        |theme, status| {
+            let colors = get_theme_colors(theme);
+            let base_style = text_editor_style(text_input_style, theme, status);
+            
+            text_editor::Style {
+                background: Background::Color(Color::TRANSPARENT),
+                border: Border {
+                    radius: 0.0.into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+                placeholder: colors.tertiary_text,
+                ..base_style
+            }
+        }
+    }
+
+    /// Get a text editor style for content with validation errors
+    pub fn danger_text_editor<'a>() -> impl Fn(&Theme, text_editor::Status) -> text_editor::Style + 'a {
+        |theme, status| text_editor_style(Self::danger_text_input(), theme, status)
+    }
+
+    /// Get a text editor style for content with validation warnings
+    pub fn warning_text_editor<'a>() -> impl Fn(&Theme, text_editor::Status) -> text_editor::Style + 'a {
+        |theme, status| text_editor_style(Self::warning_text_input(), theme, status)
+    }
+
+    /// Dynamically choose between danger, warning and standard text editor styles
+    pub fn conditional_text_editor<'a>(
+        validation_state: ValidationState
+    ) -> impl Fn(&Theme, text_editor::Status) -> text_editor::Style + 'a {
+        move |theme, status| {
+            match validation_state {
+                ValidationState::Error => (Self::danger_text_editor())(theme, status),
+                ValidationState::Warning => (Self::warning_text_editor())(theme, status),
+                ValidationState::Valid => (Self::text_editor())(theme, status),
+            }
+        }
+    }
+
+    /// Get an Modern-style theme for pane grids
+    pub fn pane_grid<'a>() -> impl Fn(&Theme) -> pane_grid::Style + 'a {
+        |theme| {
+            let colors = get_theme_colors(theme);
+            let metrics = get_theme_metrics(theme);
+            
+            let split = if is_high_contrast(theme) { colors.inactive_border } else { colors.separator };
+            
+            pane_grid::Style {
+                hovered_region: pane_grid::Highlight {
+                    background: Background::Color(colors.accent.scale_alpha(0.15)),
+                    border: Border {
+                        radius: metrics.corner_radius.into(),
+                        width: 2.0,
+                        color: colors.accent,
+                    },
+                },
+                hovered_split: pane_grid::Line {
+                    color: split,
+                    width: 2.0,
+                },
+                picked_split: pane_grid::Line {
+                    color: colors.accent,
+                    width: 2.0,
+                },
+            }
+        }
+    }
+
+    /// Get a title bar style for panes, matching `toolbar_container`
+    ///
+    /// The top corners are rounded to sit on a [`pane_body_container`](Self::pane_body_container).
+    pub fn pane_title_bar_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        |theme| {
+            let style = (Self::toolbar_container())(theme);
+            
+            container::Style {
+                border: Border {
+                    radius: border::top(get_theme_metrics(theme).card_corner_radius),
+                    ..style.border
+                },
+                // The body's shadow covers the whole pane
+                shadow: Shadow::default(),
+                ..style
+            }
+        }
+    }
+
+    /// Get a body style for panes with a title bar, matching `card_container`
+    ///
+    /// Panes without a title bar can use `card_container` directly.
+    pub fn pane_body_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        |theme| {
+            let style = (Self::card_container())(theme);
+            
+            container::Style {
+                border: Border {
+                    radius: border::bottom(get_theme_metrics(theme).card_corner_radius),
+                    ..style.border
+                },
+                ..style
+            }
+        }
+    }
+
+    /// Get a body style for the focused pane, outlined in the accent color
+    pub fn focused_pane_body_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        |theme| {
+            let style = (Self::pane_body_container())(theme);
+            
+            container::Style {
+                border: Border {
+                    width: 1.0,
+                    color: get_theme_colors(theme).accent,
+                    ..style.border
+                },
+                ..style
+            }
+        }
+    }
+
+    /// Get an Modern-style theme for SVG icons, tinted with the text color
+    #[cfg(feature = "svg")]
+    pub fn svg<'a>() -> impl Fn(&Theme, svg::Status) -> svg::Style + 'a {
+        |theme, _status| svg_tint(get_theme_colors(theme).text)
+    }
+
+    /// Get an SVG style tinted with the secondary text color
+    #[cfg(feature = "svg")]
+    pub fn secondary_svg<'a>() -> impl Fn(&Theme, svg::Status) -> svg::Style + 'a {
+        |theme, _status| svg_tint(get_theme_colors(theme).secondary_text)
+    }
+
+    /// Get an SVG style tinted with the tertiary text color
+    #[cfg(feature = "svg")]
+    pub fn tertiary_svg<'a>() -> impl Fn(&Theme, svg::Status) -> svg::Style + 'a {
+        |theme, _status| svg_tint(get_theme_colors(theme).tertiary_text)
+    }
+
+    /// Get an SVG style tinted with the accent color
+    #[cfg(feature = "svg")]
+    pub fn accent_svg<'a>() -> impl Fn(&Theme, svg::Status) -> svg::Style + 'a {
+        |theme, _status| svg_tint(get_theme_colors(theme).accent)
+    }
+
+    /// Get an SVG style tinted with a system color
+    #[cfg(feature = "svg")]
+    pub fn tinted_svg<'a>(color_variant: TintedButtonColor) -> impl Fn(&Theme, svg::Status) -> svg::Style + 'a {
+        move |theme, _status| svg_tint(tint_color(&get_theme_colors(theme), color_variant))
+    }
+
+    /// Get an SVG style colored by a validation state (green, orange or red)
+    #[cfg(feature = "svg")]
+    pub fn conditional_svg<'a>(
+        validation_state: ValidationState
+    ) -> impl Fn(&Theme, svg::Status) -> svg::Style + 'a {
+        move |theme, _status| {
+            let colors = get_theme_colors(theme);
+            
+            svg_tint(match validation_state {
+                ValidationState::Error => colors.red,
+                ValidationState::Warning => colors.orange,
+                ValidationState::Valid => colors.green,
+            })
+        }
+    }
+
+    /// Get an SVG style for icons inside a `plain_button`
+    ///
+    /// Hovering the icon fades it like the button's label. iced reports the
+    /// hover of the icon's own bounds, not the button's, so hovering the
+    /// button's padding or label leaves the icon unchanged.
+    #[cfg(feature = "svg")]
+    pub fn plain_button_svg<'a>() -> impl Fn(&Theme, svg::Status) -> svg::Style + 'a {
+        |theme, status| svg_hover_tint(theme, get_theme_colors(theme).text, status)
+    }
+
+    /// Get an SVG style for icons inside a `link_button`
+    ///
+    /// Like [`Modern::plain_button_svg`], follows only the icon's own hover.
+    #[cfg(feature = "svg")]
+    pub fn link_button_svg<'a>() -> impl Fn(&Theme, svg::Status) -> svg::Style + 'a {
+        |theme, status| svg_hover_tint(theme, get_theme_colors(theme).link, status)
+    }
+
 /*     /// Get an Modern-style theme for combo boxes
     pub fn combo_box<'a>() -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
         combo_box_style
     } */
 
     /// Create a complete Modern-styled theme
+    ///
+    /// Each call builds and registers a new theme, as do the other theme
+    /// constructors below; keep the result in your application state instead
+    /// of rebuilding it in `view`.
     pub fn theme(dark_mode: bool) -> Theme {
-        create_modern_theme(dark_mode)
+        if dark_mode {
+            ModernTheme::dark().theme()
+        } else {
+            ModernTheme::light().theme()
+        }
+    }
+
+    /// Create a named Modern-styled theme with an explicit mode
+    pub fn named_theme(name: impl Into<String>, mode: Mode) -> Theme {
+        ModernTheme::new(name, mode).theme()
+    }
+
+    /// Create a high contrast Modern-styled theme
+    ///
+    /// Uses the accessible system colors, stronger borders and separators,
+    /// opaque fills instead of translucent tints, and clearly visible focus rings.
+    pub fn high_contrast_theme(dark_mode: bool) -> Theme {
+        let theme = if dark_mode {
+            ModernTheme::new("Modern Dark High Contrast", Mode::Dark)
+        } else {
+            ModernTheme::new("Modern Light High Contrast", Mode::Light)
+        };
+
+        theme.with_high_contrast(true).theme()
+    }
+
+    /// Create a Modern-styled theme from a custom palette
+    ///
+    /// The mode is inferred from the palette background; use
+    /// [`ModernTheme::with_colors`] to set it explicitly.
+    pub fn custom_theme(name: impl Into<String>, colors: ThemeColors) -> Theme {
+        ModernTheme::new(name, Mode::from_background(colors.background))
+            .with_colors(colors)
+            .theme()
+    }
+
+    /// Check the contrast of every Modern style against a theme
+    ///
+    /// Each style is rendered in all of its statuses; the report lists the
+    /// foreground/background pairs that fall below WCAG AA or AAA.
+    pub fn audit(theme: &Theme) -> AuditReport {
+        contrast::audit(theme)
+    }
+
+    /// Create a Modern-styled theme with a custom accent color
+    ///
+    /// The accent replaces the system blue for primary buttons, focus rings,
+    /// selections, links and selected controls. The given color is used as
+    /// the light mode accent; the dark mode variant is derived from it.
+    ///
+    /// The accent is registered for the returned theme only and released with
+    /// it, so previewing accents in a live picker doesn't accumulate themes.
+    pub fn theme_with_accent(mode: Mode, accent: Color) -> Theme {
+        let name = match mode {
+            Mode::Light => "Modern Light",
+            Mode::Dark => "Modern Dark",
+        };
+
+        ModernTheme::new(name, mode)
+            .with_accent(Accent::from_color(accent))
+            .theme()
+    }
+
+    /// Create a Modern-styled theme from a single seed color
+    ///
+    /// The accent, backgrounds and borders are derived from the seed's
+    /// [`TonalScale`]; see [`TonalScale::theme_colors`].
+    pub fn theme_from_seed(mode: Mode, seed: Color) -> Theme {
+        let name = match mode {
+            Mode::Light => "Modern Light Tonal",
+            Mode::Dark => "Modern Dark Tonal",
+        };
+
+        ModernTheme::new(name, mode)
+            .with_colors(ThemeColors::from_seed(mode, seed))
+            .theme()
+    }
+
+    /// The tonal scale of a theme's accent color
+    ///
+    /// Gives custom widgets consistent tints of the accent, e.g.
+    /// `Modern::accent_scale(theme).tone(100)` for a selected row.
+    pub fn accent_scale(theme: &Theme) -> TonalScale {
+        TonalScale::from_seed(get_theme_colors(theme).accent)
+    }
+
+    /// Load a Modern-styled theme from a TOML or JSON theme file
+    ///
+    /// The format is picked from the file extension (`.json` or TOML otherwise).
+    #[cfg(feature = "theme-files")]
+    pub fn load_theme(path: impl AsRef<std::path::Path>) -> Result<Theme, ThemeFileError> {
+        ThemeFile::load(path).map(|file| file.to_modern_theme().theme())
+    }
+
+    /// Parse a Modern-styled theme from the contents of a theme file
+    #[cfg(feature = "theme-files")]
+    pub fn theme_from_str(source: &str, format: ThemeFormat) -> Result<Theme, ThemeFileError> {
+        ThemeFile::from_str(source, format).map(|file| file.to_modern_theme().theme())
+    }
+
+    /// Save a theme, including its palette, radii and shadow, to a theme file
+    ///
+    /// The format is picked from the file extension (`.json` or TOML otherwise).
+    #[cfg(feature = "theme-files")]
+    pub fn save_theme(theme: &Theme, path: impl AsRef<std::path::Path>) -> Result<(), ThemeFileError> {
+        ThemeFile::from_theme(theme).save(path)
+    }
+
+    /// Watch a theme file and emit the reloaded theme whenever it changes
+    ///
+    /// Parse errors are emitted as [`ThemeEvent::Failed`] instead of a theme,
+    /// so the application keeps its last good theme while the file is fixed.
+    ///
+    /// ```ignore
+    /// fn subscription(&self) -> Subscription<Message> {
+    ///     Modern::watch_theme("theme.toml").map(Message::ThemeReloaded)
+    /// }
+    /// ```
+    #[cfg(feature = "theme-files")]
+    pub fn watch_theme(path: impl Into<std::path::PathBuf>) -> iced::Subscription<ThemeEvent> {
+        crate::theme_file::watch(path, crate::theme_file::WATCH_INTERVAL)
     }
 
     /// Create a light Modern-styled theme
@@ -529,224 +1790,59 @@ impl Modern {
 
     // Additional button styles using more Modern colors
     
-    /// Get a teal button style (cyan-blue)
-    pub fn teal_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        move |theme, status| {
-            let colors = get_theme_colors(theme);
-            let is_dark = is_dark_mode(theme);
-            
-            let modern_base = |color: Color, text_color: Color| button::Style {
-                background: Some(Background::Color(color)),
-                text_color,
-                border: Border {
-                    radius: CORNER_RADIUS.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
-                },
-                shadow: Shadow {
-                    color: Color { a: 0.1, ..Color::BLACK },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
-                },
-            };
-            
-            let base_style = modern_base(colors.teal, Color::WHITE);
-            
-            match status {
-                ButtonStatus::Active => base_style,
-                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
-                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark),
-                ButtonStatus::Disabled => button_disabled_style(base_style),
-            }
-        }
+    /// Get a filled button style in any color
+    ///
+    /// Takes a single color or a light/dark pair, e.g.
+    /// `Accent::new(system::YELLOW, system::YELLOW_DARK)`. The label is
+    /// white, or black where white would be hard to read.
+    pub fn filled_button<'a>(color: impl Into<Accent>) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let color = color.into();
+        
+        move |theme, status| filled_button_style(theme, color.for_mode(theme_mode(theme)), status)
+    }
+    
+    /// Get a tinted button style (semi-transparent background) in any color
+    pub fn tinted_button_with<'a>(color: impl Into<Accent>) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let color = color.into();
+        
+        move |theme, status| tinted_button_style(theme, color.for_mode(theme_mode(theme)), status)
+    }
+    
+    /// Get an outlined button style in any color
+    pub fn outlined_button<'a>(color: impl Into<Accent>) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        let color = color.into();
+        
+        move |theme, status| outlined_button_style(theme, color.for_mode(theme_mode(theme)), status)
+    }
+    
+    /// Get a teal button style (cyan-blue)
+    pub fn teal_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        |theme, status| filled_button_style(theme, get_theme_colors(theme).teal, status)
     }
     
     /// Get an indigo button style (blue-purple)
     pub fn indigo_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        move |theme, status| {
-            let colors = get_theme_colors(theme);
-            let is_dark = is_dark_mode(theme);
-            
-            let modern_base = |color: Color, text_color: Color| button::Style {
-                background: Some(Background::Color(color)),
-                text_color,
-                border: Border {
-                    radius: CORNER_RADIUS.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
-                },
-                shadow: Shadow {
-                    color: Color { a: 0.1, ..Color::BLACK },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
-                },
-            };
-            
-            let base_style = modern_base(colors.indigo, Color::WHITE);
-            
-            match status {
-                ButtonStatus::Active => base_style,
-                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
-                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark),
-                ButtonStatus::Disabled => button_disabled_style(base_style),
-            }
-        }
+        |theme, status| filled_button_style(theme, get_theme_colors(theme).indigo, status)
     }
     
     /// Get a purple button style
     pub fn purple_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        move |theme, status| {
-            let colors = get_theme_colors(theme);
-            let is_dark = is_dark_mode(theme);
-            
-            let modern_base = |color: Color, text_color: Color| button::Style {
-                background: Some(Background::Color(color)),
-                text_color,
-                border: Border {
-                    radius: CORNER_RADIUS.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
-                },
-                shadow: Shadow {
-                    color: Color { a: 0.1, ..Color::BLACK },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
-                },
-            };
-            
-            let base_style = modern_base(colors.purple, Color::WHITE);
-            
-            match status {
-                ButtonStatus::Active => base_style,
-                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
-                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark),
-                ButtonStatus::Disabled => button_disabled_style(base_style),
-            }
-        }
+        |theme, status| filled_button_style(theme, get_theme_colors(theme).purple, status)
     }
     
     /// Get a pink button style
     pub fn pink_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        move |theme, status| {
-            let colors = get_theme_colors(theme);
-            let is_dark = is_dark_mode(theme);
-            
-            let modern_base = |color: Color, text_color: Color| button::Style {
-                background: Some(Background::Color(color)),
-                text_color,
-                border: Border {
-                    radius: CORNER_RADIUS.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
-                },
-                shadow: Shadow {
-                    color: Color { a: 0.1, ..Color::BLACK },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
-                },
-            };
-            
-            let base_style = modern_base(colors.pink, Color::WHITE);
-            
-            match status {
-                ButtonStatus::Active => base_style,
-                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
-                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark),
-                ButtonStatus::Disabled => button_disabled_style(base_style),
-            }
-        }
+        |theme, status| filled_button_style(theme, get_theme_colors(theme).pink, status)
     }
 
-     /// Get an Modern-style gray button (neutral, subdued appearance)
-     pub fn gray_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        move |theme, status| {
-            let colors = get_theme_colors(theme);
-            let is_dark = is_dark_mode(theme);
-            
-            // Gray color varies by theme
-            let gray_color = if is_dark {
-                colors::gray::GRAY3_DARK
-            } else {
-                colors::gray::GRAY4_LIGHT
-            };
-            
-            let modern_base = |color: Color, text_color: Color| button::Style {
-                background: Some(Background::Color(color)),
-                text_color,
-                border: Border {
-                    radius: CORNER_RADIUS.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
-                },
-                shadow: Shadow {
-                    color: Color { a: 0.1, ..Color::BLACK }, 
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
-                },
-            };
-            
-            let base_style = modern_base(gray_color, colors.text);
-            
-            match status {
-                ButtonStatus::Active => base_style,
-                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
-                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark),
-                ButtonStatus::Disabled => button_disabled_style(base_style),
-            }
-        }
+    /// Get an Modern-style gray button (neutral, subdued appearance)
+    pub fn gray_button<'a>() -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
+        |theme, status| filled_button_style(theme, get_theme_colors(theme).gray, status)
     }
     
     /// Get an Modern-style tinted button (semi-transparent colored background)
     pub fn tinted_button<'a>(color_variant: TintedButtonColor) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
-        move |theme, status| {
-            let colors = get_theme_colors(theme);
-            let is_dark = is_dark_mode(theme);
-            
-            // Get the base color based on the variant
-            let (base_color, text_color) = match color_variant {
-                TintedButtonColor::Blue => (colors.blue, Color::WHITE),
-                TintedButtonColor::Green => (colors.green, Color::WHITE),
-                TintedButtonColor::Red => (colors.red, Color::WHITE),
-                TintedButtonColor::Orange => (colors.orange, Color::WHITE),
-                TintedButtonColor::Purple => (colors.purple, Color::WHITE),
-                TintedButtonColor::Teal => (colors.teal, Color::WHITE),
-                TintedButtonColor::Pink => (colors.pink, Color::WHITE),
-                TintedButtonColor::Indigo => (colors.indigo, Color::WHITE),
-            };
-            
-            // Make color semi-transparent for tinted look
-            let tinted_color = Color {
-                r: base_color.r,
-                g: base_color.g,
-                b: base_color.b,
-                a: 0.2, // Low opacity for tinted appearance
-            };
-            
-            // For tinted buttons, we usually want a stronger text color
-            let modern_base = |color: Color, text_color: Color| button::Style {
-                background: Some(Background::Color(color)),
-                text_color,
-                border: Border {
-                    radius: CORNER_RADIUS.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
-                },
-                shadow: Shadow {
-                    color: Color { a: 0.05, ..Color::BLACK }, // Lighter shadow for tinted
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
-                },
-            };
-            
-            let base_style = modern_base(tinted_color, base_color); // Use the base color for text
-            
-            match status {
-                ButtonStatus::Active => base_style,
-                ButtonStatus::Hovered => button_hover_style(base_style, is_dark),
-                ButtonStatus::Pressed => button_pressed_style(base_style, is_dark),
-                ButtonStatus::Disabled => button_disabled_style(base_style),
-            }
-        }
+        move |theme, status| tinted_button_style(theme, tint_color(&get_theme_colors(theme), color_variant), status)
     }
     
     /// Get an Modern-style blue tinted button
@@ -795,15 +1891,12 @@ impl Modern {
         size: ButtonSize
     ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
         move |theme, status| {
+            let metrics = get_theme_metrics(theme);
             let mut base_style = style_fn(theme, status);
             
             // Modify the border radius based on size
             base_style.border = Border {
-                radius: match size {
-                    ButtonSize::Small => (CORNER_RADIUS * 0.8).into(),
-                    ButtonSize::Medium => CORNER_RADIUS.into(),
-                    ButtonSize::Large => (CORNER_RADIUS * 1.2).into(),
-                },
+                radius: (metrics.corner_radius * size.radius_scale()).into(),
                 ..base_style.border
             };
             
@@ -811,6 +1904,50 @@ impl Modern {
         }
     }
 
+    /// Build a text button at a size, styled and rounded for that size
+    ///
+    /// ```ignore
+    /// Modern::sized_label_button("Get Started", ButtonSize::ExtraLarge, Modern::primary_button())
+    ///     .on_press(Message::Start)
+    /// ```
+    pub fn sized_label_button<'a, Message, T>(
+        label: impl text::IntoFragment<'a>,
+        size: ButtonSize,
+        style: impl Fn(&Theme, ButtonStatus) -> button::Style + 'a,
+    ) -> Button<'a, Message, T>
+    where
+        Message: 'a,
+        T: button::Catalog + text::Catalog + 'a,
+        <T as button::Catalog>::Class<'a>: From<button::StyleFn<'a, Theme>>,
+    {
+        sized_button_with(text(label).size(size.text_size()), size, style)
+    }
+
+    /// Build a button with a leading icon and a label at a size
+    ///
+    /// The icon is centered in a square of [`ButtonSize::icon_size`]; give
+    /// svgs and images `Length::Fill` to scale them into it. Text glyphs keep
+    /// their own font size.
+    pub fn sized_icon_button<'a, Message, T>(
+        icon: impl Into<Element<'a, Message, T>>,
+        label: impl text::IntoFragment<'a>,
+        size: ButtonSize,
+        style: impl Fn(&Theme, ButtonStatus) -> button::Style + 'a,
+    ) -> Button<'a, Message, T>
+    where
+        Message: 'a,
+        T: button::Catalog + container::Catalog + text::Catalog + 'a,
+        <T as button::Catalog>::Class<'a>: From<button::StyleFn<'a, Theme>>,
+    {
+        let content = Row::new()
+            .push(icon_slot(icon, size))
+            .push(text(label).size(size.text_size()))
+            .spacing(size.icon_size() / 3.0)
+            .align_y(Vertical::Center);
+        
+        sized_button_with(content, size, style)
+    }
+
 
     /// Create a "selected" version of any button style
     /// 
@@ -821,13 +1958,11 @@ impl Modern {
         base_style_fn: impl Fn(&Theme, ButtonStatus) -> button::Style + 'a
     ) -> impl Fn(&Theme, ButtonStatus) -> button::Style + 'a {
         move |theme, status| {
-            let is_dark = is_dark_mode(theme);
-            
             match status {
                 ButtonStatus::Active => {
                     // For the active state, use what would normally be the pressed state
                     let base_style = base_style_fn(theme, ButtonStatus::Active);
-                    button_pressed_style(base_style, is_dark)
+                    button_pressed_style(base_style, theme)
                 },
                 // For other states, use the original style function
                 _ => base_style_fn(theme, status),
@@ -835,9 +1970,35 @@ impl Modern {
         }
     }
     
+    /// Create a hovered version of any container style
+    ///
+    /// Containers have no status of their own; use this for clickable rows
+    /// and tiles (e.g. inside a `mouse_area`) so they respond like buttons.
+    pub fn hovered_container<'a>(
+        base_style_fn: impl Fn(&Theme) -> container::Style + 'a
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        move |theme| {
+            let delta = get_theme_metrics(theme).states.hovered;
+            container_state_style(theme, base_style_fn(theme), delta)
+        }
+    }
+
+    /// Create a pressed version of any container style
+    pub fn pressed_container<'a>(
+        base_style_fn: impl Fn(&Theme) -> container::Style + 'a
+    ) -> impl Fn(&Theme) -> container::Style + 'a {
+        move |theme| {
+            let delta = get_theme_metrics(theme).states.pressed;
+            container_state_style(theme, base_style_fn(theme), delta)
+        }
+    }
+    
     // Container variants
     
     /// Get a container with separator line style
+    ///
+    /// iced borders go around every edge, so the separator frames the whole
+    /// container. Use [`Modern::separated`] for separators between rows.
     pub fn separated_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
         move |theme| {
             let colors = get_theme_colors(theme);
@@ -847,8 +2008,8 @@ impl Modern {
                 background: Some(Background::Color(colors.background)),
                 border: Border {
                     radius: 0.0.into(),
-                    width: 0.0,
-                    color: Color::TRANSPARENT,
+                    width: 1.0,
+                    color: colors.separator,
                 },
                 shadow: Shadow::default(),
             }
@@ -859,14 +2020,15 @@ impl Modern {
     pub fn accent_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
         move |theme| {
             let colors = get_theme_colors(theme);
+            let metrics = get_theme_metrics(theme);
             
             container::Style {
                 text_color: Some(colors.text),
                 background: Some(Background::Color(colors.background)),
                 border: Border {
-                    radius: 8.0.into(),
+                    radius: metrics.corner_radius.into(),
                     width: 2.0,
-                    color: colors.blue,
+                    color: colors.accent,
                 },
                 shadow: Shadow {
                     color: Color { a: 0.1, ..Color::BLACK },
@@ -903,12 +2065,13 @@ impl Modern {
     pub fn floating_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
         move |theme| {
             let colors = get_theme_colors(theme);
+            let metrics = get_theme_metrics(theme);
             
             container::Style {
                 text_color: Some(colors.text),
                 background: Some(Background::Color(colors.card_bg)),
                 border: Border {
-                    radius: 10.0.into(),
+                    radius: metrics.card_corner_radius.into(),
                     width: 0.0,
                     color: Color::TRANSPARENT,
                 },
@@ -925,6 +2088,7 @@ impl Modern {
     pub fn danger_tooltip_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
         move |theme| {
             let colors = get_theme_colors(theme);
+            let metrics = get_theme_metrics(theme);
     
             // Determine if dark mode
             let dark_mode = is_dark_mode(theme);
@@ -943,7 +2107,7 @@ impl Modern {
                     }
                 )),
                 border: Border {
-                    radius: 6.0.into(),
+                    radius: metrics.small_corner_radius.into(),
                     width: 1.0,
                     color: if dark_mode {
                         Color::from_rgb(0.8, 0.3, 0.3)
@@ -964,6 +2128,7 @@ impl Modern {
     pub fn warning_tooltip_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
         move |theme| {
             let colors = get_theme_colors(theme);
+            let metrics = get_theme_metrics(theme);
             
             container::Style {
                 text_color: Some(colors.orange),  // Orange text for warnings
@@ -977,15 +2142,11 @@ impl Modern {
                     }
                 )),
                 border: Border {
-                    radius: 6.0.into(),  // Slightly rounded corners
+                    radius: metrics.small_corner_radius.into(),  // Slightly rounded corners
                     width: 1.0,
                     color: colors.orange,  // Orange border to match the warning theme
                 },
-                shadow: Shadow {
-                    color: Color { a: 0.1, ..Color::BLACK },
-                    offset: Vector::new(0.0, 1.0),
-                    blur_radius: 2.0,
-                },
+                shadow: metrics.shadow,
             }
         }
     }
@@ -1027,6 +2188,91 @@ impl Modern {
             }
         }
     }
+
+    /// Get an informational tooltip container style
+    pub fn info_tooltip_container<'a>() -> impl Fn(&Theme) -> container::Style + 'a {
+        move |theme| {
+            let colors = get_theme_colors(theme);
+            let metrics = get_theme_metrics(theme);
+            
+            container::Style {
+                text_color: Some(colors.text),
+                background: Some(Background::Color(colors.card_bg)),
+                border: Border {
+                    radius: metrics.small_corner_radius.into(),
+                    width: if is_high_contrast(theme) { 1.0 } else { 0.5 },
+                    color: colors.inactive_border,
+                },
+                shadow: metrics.shadow,
+            }
+        }
+    }
+
+    /// Build an informational tooltip showing `label` next to `content`
+    ///
+    /// The bubble has consistent padding, caption-sized text wrapped at a
+    /// maximum width, and appears after a short delay.
+    pub fn tooltip<'a, Message, T>(
+        content: impl Into<Element<'a, Message, T>>,
+        label: impl text::IntoFragment<'a>,
+        position: tooltip::Position,
+    ) -> Tooltip<'a, Message, T>
+    where
+        Message: 'a,
+        T: container::Catalog + text::Catalog + 'a,
+        <T as container::Catalog>::Class<'a>: From<container::StyleFn<'a, Theme>>,
+    {
+        themed_tooltip(content, label, position, Box::new(Self::info_tooltip_container()))
+    }
+
+    /// Build a tooltip explaining a validation error
+    pub fn danger_tooltip<'a, Message, T>(
+        content: impl Into<Element<'a, Message, T>>,
+        label: impl text::IntoFragment<'a>,
+        position: tooltip::Position,
+    ) -> Tooltip<'a, Message, T>
+    where
+        Message: 'a,
+        T: container::Catalog + text::Catalog + 'a,
+        <T as container::Catalog>::Class<'a>: From<container::StyleFn<'a, Theme>>,
+    {
+        themed_tooltip(content, label, position, Box::new(Self::danger_tooltip_container()))
+    }
+
+    /// Build a tooltip explaining a validation warning
+    pub fn warning_tooltip<'a, Message, T>(
+        content: impl Into<Element<'a, Message, T>>,
+        label: impl text::IntoFragment<'a>,
+        position: tooltip::Position,
+    ) -> Tooltip<'a, Message, T>
+    where
+        Message: 'a,
+        T: container::Catalog + text::Catalog + 'a,
+        <T as container::Catalog>::Class<'a>: From<container::StyleFn<'a, Theme>>,
+    {
+        themed_tooltip(content, label, position, Box::new(Self::warning_tooltip_container()))
+    }
+
+    /// Build a tooltip styled by a validation state
+    ///
+    /// Valid content gets the informational tooltip.
+    pub fn conditional_tooltip<'a, Message, T>(
+        content: impl Into<Element<'a, Message, T>>,
+        label: impl text::IntoFragment<'a>,
+        position: tooltip::Position,
+        validation_state: ValidationState,
+    ) -> Tooltip<'a, Message, T>
+    where
+        Message: 'a,
+        T: container::Catalog + text::Catalog + 'a,
+        <T as container::Catalog>::Class<'a>: From<container::StyleFn<'a, Theme>>,
+    {
+        match validation_state {
+            ValidationState::Error => Self::danger_tooltip(content, label, position),
+            ValidationState::Warning => Self::warning_tooltip(content, label, position),
+            ValidationState::Valid => Self::tooltip(content, label, position),
+        }
+    }
     
     
     // Text input variants
@@ -1035,11 +2281,12 @@ impl Modern {
     pub fn search_input<'a>() -> impl Fn(&Theme, TextInputStatus) -> text_input::Style + 'a {
         move |theme, status| {
             let colors = get_theme_colors(theme);
+            let metrics = get_theme_metrics(theme);
             
             let base_style = text_input::Style {
                 background: Background::Color(colors.system_bg),
                 border: Border {
-                    radius: CORNER_RADIUS.into(),
+                    radius: metrics.corner_radius.into(),
                     width: 0.0,
                     color: Color::TRANSPARENT,
                 },
@@ -1051,7 +2298,19 @@ impl Modern {
             
             match status {
                 TextInputStatus::Active => base_style,
-                TextInputStatus::Hovered => base_style,
+                TextInputStatus::Hovered => text_input::Style {
+                    background: input_hover_background(theme, base_style.background),
+                    ..base_style
+                },
+                TextInputStatus::Focused { is_hovered: _ } if is_high_contrast(theme) => text_input::Style {
+                    background: Background::Color(colors.tertiary_background),
+                    border: Border {
+                        color: colors.focus_ring,
+                        width: 2.0,
+                        ..base_style.border
+                    },
+                    ..base_style
+                },
                 TextInputStatus::Focused { is_hovered: _ } => text_input::Style {
                     background: Background::Color(colors.tertiary_background),
                     ..base_style
@@ -1094,7 +2353,7 @@ impl Modern {
                 },
                 TextInputStatus::Focused { is_hovered: _ }  => text_input::Style {
                     border: Border {
-                        color: colors.blue,
+                        color: colors.focus_ring,
                         width: 2.0,
                         ..base_style.border
                     },
@@ -1343,22 +2602,10 @@ impl Modern {
     }
 
     /// Get a modern theme for combo box menus
+    ///
+    /// The same as [`Modern::pick_list_menu`], so every dropdown looks alike.
     pub fn combo_box_menu<'a>() -> impl Fn(&Theme) -> menu::Style + 'a {
-        |theme| {
-            let colors = get_theme_colors(theme);
-            
-            menu::Style {
-                text_color: colors.text,
-                background: Background::Color(colors.card_bg),
-                border: Border {
-                    radius: TINY_CORNER_RADIUS.into(),
-                    width: 1.0,
-                    color: colors.input_border,
-                },
-                selected_text_color: Color::WHITE,
-                selected_background: Background::Color(colors.blue),
-            }
-        }
+        Self::pick_list_menu()
     }
 
     /// Conditional button style helper specifically for button styles
@@ -1411,30 +2658,29 @@ impl Modern {
 /// Modern design-inspired button style implementation
 fn button_style(theme: &Theme, class: &style::Button, status: ButtonStatus) -> button::Style {
     let colors = get_theme_colors(theme);
-    let is_dark = is_dark_mode(theme);
+    let metrics = get_theme_metrics(theme);
     
     // Function to create the base Modern style with rounded corners
     let modern_base = |color: Color, text_color: Color| button::Style {
         background: Some(Background::Color(color)),
         text_color,
         border: Border {
-            radius: CORNER_RADIUS.into(), // Modern's rounded corners
+            radius: metrics.corner_radius.into(), // Modern's rounded corners
             width: 0.0,         // No border for filled buttons
             color: Color::TRANSPARENT,
         },
-        shadow: Shadow {
-            color: Color { a: 0.1, ..Color::BLACK }, 
-            offset: Vector::new(0.0, 1.0),
-            blur_radius: 2.0,
-        },
+        shadow: metrics.shadow,
     };
     
+    // Filled in a role color, with whichever label reads best on it
+    let filled = |color: Color| modern_base(color, contrast::readable_text(color));
+    
     // Function to create outlined style
     let outlined = |color: Color, text_color: Color| button::Style {
         background: Some(Background::Color(Color::TRANSPARENT)),
         text_color,
         border: Border {
-            radius: CORNER_RADIUS.into(),
+            radius: metrics.corner_radius.into(),
             width: 1.0,
             color,
         },
@@ -1451,124 +2697,105 @@ fn button_style(theme: &Theme, class: &style::Button, status: ButtonStatus) -> b
     
     // Base style based on button class
     let base_style = match class {
-        style::Button::Primary => modern_base(colors.blue, Color::WHITE),
-        style::Button::Secondary => outlined(colors.blue, colors.blue),
-        style::Button::Success => modern_base(colors.green, Color::WHITE),
-        style::Button::Warning => modern_base(colors.orange, if is_dark { Color::BLACK } else { Color::WHITE }),
-        style::Button::Danger => modern_base(colors.red, Color::WHITE),
-        style::Button::Link => transparent(colors.blue),
+        style::Button::Primary => filled(colors.accent),
+        style::Button::Secondary => outlined(colors.accent, colors.accent),
+        style::Button::Success => filled(colors.green),
+        style::Button::Warning => filled(colors.orange),
+        style::Button::Danger => filled(colors.red),
+        style::Button::Link => transparent(colors.link),
         style::Button::System => modern_base(colors.system_bg, colors.text),
         style::Button::Plain => transparent(colors.text),
     };
     
     // Adjust style based on status
-    match status {
-        ButtonStatus::Active => base_style,
-        
-        ButtonStatus::Hovered => {
-            // For Modern style, make buttons slightly lighter/darker on hover
-            let adjust_color = |color: Color| -> Color {
-                if is_dark {
-                    // Lighten in dark mode
-                    Color {
-                        r: (color.r + 0.05).min(1.0),
-                        g: (color.g + 0.05).min(1.0),
-                        b: (color.b + 0.05).min(1.0),
-                        a: color.a,
-                    }
-                } else {
-                    // Darken in light mode
-                    Color {
-                        r: (color.r - 0.05).max(0.0),
-                        g: (color.g - 0.05).max(0.0),
-                        b: (color.b - 0.05).max(0.0),
-                        a: color.a,
-                    }
-                }
-            };
-            
-            match class {
-                style::Button::Link | style::Button::Plain => {
-                    // For text/links, just adjust the text color
-                    button::Style {
-                        text_color: base_style.text_color.scale_alpha(0.8),
-                        ..base_style
-                    }
-                },
-                _ => {
-                    // For other buttons, adjust the background
-                    if let Some(Background::Color(color)) = base_style.background {
-                        button::Style {
-                            background: Some(Background::Color(adjust_color(color))),
-                            ..base_style
-                        }
-                    } else {
-                        base_style
-                    }
-                }
-            }
+    button_status_style(base_style, theme, status)
+}
+
+/// Modern button style for a role drawn at an emphasis level
+fn emphasized_button_style(
+    theme: &Theme,
+    class: &style::Button,
+    emphasis: style::Emphasis,
+    status: ButtonStatus,
+) -> button::Style {
+    let colors = get_theme_colors(theme);
+    let metrics = get_theme_metrics(theme);
+    
+    let color = match class {
+        style::Button::Primary | style::Button::Secondary => colors.accent,
+        style::Button::Success => colors.green,
+        style::Button::Warning => colors.orange,
+        style::Button::Danger => colors.red,
+        style::Button::Link => colors.link,
+        style::Button::System => colors.gray,
+        style::Button::Plain => colors.text,
+    };
+    
+    let style = match emphasis {
+        style::Emphasis::Filled => match class {
+            // These roles are filled already
+            style::Button::Primary
+            | style::Button::Success
+            | style::Button::Warning
+            | style::Button::Danger
+            | style::Button::System => return button_style(theme, class, status),
+            // A gray fill with an accent label, so it stays distinct from primary
+            style::Button::Secondary => {
+                let base_style = button::Style {
+                    background: Some(Background::Color(colors.system_bg)),
+                    text_color: colors.accent,
+                    border: Border {
+                        radius: metrics.corner_radius.into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    shadow: metrics.shadow,
+                };
+                
+                button_status_style(base_style, theme, status)
+            },
+            _ => filled_button_style(theme, color, status),
         },
-        
-        ButtonStatus::Pressed => {
-            // For pressed state, make buttons even more light/dark and reduce shadow
-            let adjust_color = |color: Color| -> Color {
-                if is_dark {
-                    // Lighten more in dark mode
-                    Color {
-                        r: (color.r + 0.1).min(1.0),
-                        g: (color.g + 0.1).min(1.0),
-                        b: (color.b + 0.1).min(1.0),
-                        a: color.a,
-                    }
-                } else {
-                    // Darken more in light mode
-                    Color {
-                        r: (color.r - 0.1).max(0.0),
-                        g: (color.g - 0.1).max(0.0),
-                        b: (color.b - 0.1).max(0.0),
-                        a: color.a,
-                    }
-                }
+        style::Emphasis::Tinted => tinted_button_style(theme, color, status),
+        style::Emphasis::Outlined => outlined_button_style(theme, color, status),
+        style::Emphasis::Ghost => {
+            let base_style = button::Style {
+                background: None,
+                text_color: color,
+                border: Border {
+                    radius: metrics.corner_radius.into(),
+                    width: 0.0,
+                    color: Color::TRANSPARENT,
+                },
+                shadow: Shadow::default(),
             };
             
-            let mut pressed_style = base_style;
-            
-            // Remove shadow when pressed (Modern's buttons appear to press down)
-            pressed_style.shadow = Shadow::default();
+            // The fill grows with the theme's state deltas, to 0.12 alpha when hovered by default
+            let fill = |delta: f32| button::Style {
+                background: Some(Background::Color(color.scale_alpha(delta * 3.0))),
+                ..base_style
+            };
             
-            match class {
-                style::Button::Link | style::Button::Plain => {
-                    // For text/links, just adjust the text color more
-                    pressed_style.text_color = base_style.text_color.scale_alpha(0.6);
-                    pressed_style
-                },
-                _ => {
-                    // For other buttons, adjust the background more
-                    if let Some(Background::Color(color)) = base_style.background {
-                        pressed_style.background = Some(Background::Color(adjust_color(color)));
-                    }
-                    pressed_style
-                }
+            match status {
+                ButtonStatus::Active => base_style,
+                ButtonStatus::Hovered => fill(metrics.states.hovered),
+                ButtonStatus::Pressed => fill(metrics.states.pressed),
+                ButtonStatus::Disabled => button_disabled_style(base_style),
             }
         },
+    };
+    
+    // Gray labels are too faint, so system buttons keep the text color
+    if *class == style::Button::System && !is_high_contrast(theme) && emphasis != style::Emphasis::Filled {
+        let text_color = match status {
+            ButtonStatus::Disabled => colors.text.scale_alpha(0.5),
+            _ => colors.text,
+        };
         
-        ButtonStatus::Disabled => {
-            // For disabled state, reduce opacity
-            button::Style {
-                background: base_style.background.map(|bg| match bg {
-                    Background::Color(color) => Background::Color(color.scale_alpha(0.5)),
-                    _ => bg,
-                }),
-                text_color: base_style.text_color.scale_alpha(0.5),
-                border: Border {
-                    color: base_style.border.color.scale_alpha(0.5),
-                    ..base_style.border
-                },
-                shadow: Shadow::default(), // No shadow for disabled buttons
-            }
-        },
+        button::Style { text_color, ..style }
+    } else {
+        style
     }
-    
 }
 
 // Define an enum for validation states
@@ -1578,3 +2805,45 @@ pub enum ValidationState {
     Warning,
     Error,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iced::{Length, Size};
+
+    const SIZES: [ButtonSize; 5] = [
+        ButtonSize::ExtraSmall,
+        ButtonSize::Small,
+        ButtonSize::Medium,
+        ButtonSize::Large,
+        ButtonSize::ExtraLarge,
+    ];
+
+    #[test]
+    fn icon_slot_matches_button_size() {
+        for size in SIZES {
+            let slot: Element<'_, (), Theme> = icon_slot(Space::new(), size).into();
+            let side = Length::Fixed(size.icon_size());
+
+            assert_eq!(slot.as_widget().size(), Size::new(side, side), "{size:?}");
+        }
+    }
+
+    #[test]
+    fn button_sizes_fit_their_content() {
+        for size in SIZES {
+            let inner = size.min_height() - size.padding().y();
+
+            assert!(size.icon_size() <= inner, "{size:?} icon overflows");
+            assert!(size.text_size() <= inner, "{size:?} label overflows");
+        }
+
+        for pair in SIZES.windows(2) {
+            let [smaller, larger] = [pair[0], pair[1]];
+
+            assert!(smaller.icon_size() < larger.icon_size());
+            assert!(smaller.text_size() < larger.text_size());
+            assert!(smaller.min_height() < larger.min_height());
+        }
+    }
+}
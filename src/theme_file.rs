@@ -0,0 +1,837 @@
+//! Loading and saving Modern themes from TOML and JSON files.
+//!
+//! A theme file describes a complete Modern theme: its name and mode, any
+//! overridden [`ThemeColors`] roles, the corner radii, the control shadow and
+//! the hovered/pressed lightness deltas.
+//! Colors are written as hex strings (`"#007AFF"`, `"#007AFF80"`) or as
+//! `rgb(0, 122, 255)` / `rgba(0, 122, 255, 0.5)` values. Roles that are left
+//! out keep the default colors of the mode.
+//!
+//! ```toml
+//! name = "Brand Dark"
+//! mode = "dark"
+//!
+//! [colors]
+//! accent = "#7D3CFF"
+//! background = "#15131A"
+//!
+//! [radii]
+//! corner = 10.0
+//! card = 14.0
+//!
+//! [shadow]
+//! color = "rgba(0, 0, 0, 0.2)"
+//! offset_y = 2.0
+//! blur_radius = 4.0
+//!
+//! [states]
+//! hovered = 0.05
+//! pressed = 0.1
+//! ```
+//!
+//! During development, [`watch`] keeps a running application in sync with a
+//! theme file on disk.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use iced::futures::channel::mpsc;
+use iced::futures::Stream;
+use iced::{Color, Shadow, Subscription, Theme, Vector};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::colors::{
+    get_theme_colors, get_theme_metrics, is_high_contrast, theme_mode, Accent, Mode, ThemeColors,
+};
+use crate::styles::{Metrics, StateDeltas};
+use crate::theme::ModernTheme;
+
+/// Every color role of [`ThemeColors`] that can appear in a theme file
+pub const COLOR_ROLES: &[&str] = &[
+    "accent",
+    "blue",
+    "green",
+    "orange",
+    "red",
+    "text",
+    "secondary_text",
+    "tertiary_text",
+    "background",
+    "secondary_background",
+    "tertiary_background",
+    "card_bg",
+    "sheet_bg",
+    "group_bg",
+    "sidebar_bg",
+    "system_bg",
+    "inactive_border",
+    "placeholder",
+    "input_bg",
+    "input_border",
+    "focus_ring",
+    "separator",
+    "selection",
+    "link",
+    "gray",
+    "pink",
+    "purple",
+    "indigo",
+    "teal",
+    "mint",
+    "yellow",
+    "brown",
+];
+
+/// Access a color role of a palette by its name in theme files
+fn color_role<'a>(colors: &'a mut ThemeColors, role: &str) -> Option<&'a mut Color> {
+    Some(match role {
+        "accent" => &mut colors.accent,
+        "blue" => &mut colors.blue,
+        "green" => &mut colors.green,
+        "orange" => &mut colors.orange,
+        "red" => &mut colors.red,
+        "text" => &mut colors.text,
+        "secondary_text" => &mut colors.secondary_text,
+        "tertiary_text" => &mut colors.tertiary_text,
+        "background" => &mut colors.background,
+        "secondary_background" => &mut colors.secondary_background,
+        "tertiary_background" => &mut colors.tertiary_background,
+        "card_bg" => &mut colors.card_bg,
+        "sheet_bg" => &mut colors.sheet_bg,
+        "group_bg" => &mut colors.group_bg,
+        "sidebar_bg" => &mut colors.sidebar_bg,
+        "system_bg" => &mut colors.system_bg,
+        "inactive_border" => &mut colors.inactive_border,
+        "placeholder" => &mut colors.placeholder,
+        "input_bg" => &mut colors.input_bg,
+        "input_border" => &mut colors.input_border,
+        "focus_ring" => &mut colors.focus_ring,
+        "separator" => &mut colors.separator,
+        "selection" => &mut colors.selection,
+        "link" => &mut colors.link,
+        "gray" => &mut colors.gray,
+        "pink" => &mut colors.pink,
+        "purple" => &mut colors.purple,
+        "indigo" => &mut colors.indigo,
+        "teal" => &mut colors.teal,
+        "mint" => &mut colors.mint,
+        "yellow" => &mut colors.yellow,
+        "brown" => &mut colors.brown,
+        _ => return None,
+    })
+}
+
+/// File formats supported for theme files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeFormat {
+    Toml,
+    Json,
+}
+
+impl ThemeFormat {
+    /// Pick the format from the file extension, defaulting to TOML
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => ThemeFormat::Json,
+            _ => ThemeFormat::Toml,
+        }
+    }
+}
+
+/// Errors produced while loading or saving theme files
+#[derive(Debug)]
+pub enum ThemeFileError {
+    /// The file could not be read or written
+    Io(std::io::Error),
+    /// The file is malformed
+    Parse {
+        /// Dotted path of the offending key, e.g. `colors.accent`
+        key: Option<String>,
+        /// 1-based line of the problem, when known
+        line: Option<usize>,
+        /// Description of the problem
+        message: String,
+    },
+    /// The theme could not be written in the requested format
+    Serialize(String),
+}
+
+impl fmt::Display for ThemeFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeFileError::Io(error) => write!(f, "failed to access theme file: {error}"),
+            ThemeFileError::Parse { key, line, message } => {
+                write!(f, "invalid theme file")?;
+
+                if let Some(line) = line {
+                    write!(f, " at line {line}")?;
+                }
+
+                if let Some(key) = key {
+                    write!(f, " (key `{key}`)")?;
+                }
+
+                write!(f, ": {message}")
+            }
+            ThemeFileError::Serialize(message) => write!(f, "failed to write theme: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ThemeFileError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ThemeFileError {
+    fn from(error: std::io::Error) -> Self {
+        ThemeFileError::Io(error)
+    }
+}
+
+/// A color written as `#RRGGBB`, `#RRGGBBAA`, `rgb(...)` or `rgba(...)`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FileColor(pub Color);
+
+impl FileColor {
+    /// Parse a color from its theme file representation
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let value = value.trim();
+
+        if let Some(hex) = value.strip_prefix('#') {
+            return parse_hex(hex).map(FileColor);
+        }
+
+        let (arguments, has_alpha) = if let Some(rest) = value.strip_prefix("rgba(") {
+            (rest, true)
+        } else if let Some(rest) = value.strip_prefix("rgb(") {
+            (rest, false)
+        } else {
+            return Err(format!("expected a `#RRGGBB` hex string or `rgba(...)`, found `{value}`"));
+        };
+
+        let arguments = arguments
+            .strip_suffix(')')
+            .ok_or_else(|| format!("missing closing parenthesis in `{value}`"))?;
+
+        let components: Vec<&str> = arguments.split(',').map(str::trim).collect();
+        let expected = if has_alpha { 4 } else { 3 };
+
+        if components.len() != expected {
+            return Err(format!(
+                "expected {expected} components in `{value}`, found {}",
+                components.len()
+            ));
+        }
+
+        let channel = |component: &str| {
+            component
+                .parse::<u8>()
+                .map_err(|_| format!("invalid color channel `{component}` (expected 0-255)"))
+        };
+
+        let alpha = if has_alpha {
+            let alpha = components[3]
+                .parse::<f32>()
+                .map_err(|_| format!("invalid alpha `{}` (expected 0.0-1.0)", components[3]))?;
+
+            if !(0.0..=1.0).contains(&alpha) {
+                return Err(format!("alpha `{alpha}` is out of range (expected 0.0-1.0)"));
+            }
+
+            alpha
+        } else {
+            1.0
+        };
+
+        Ok(FileColor(Color::from_rgba8(
+            channel(components[0])?,
+            channel(components[1])?,
+            channel(components[2])?,
+            alpha,
+        )))
+    }
+}
+
+/// Parse the digits of a `#RGB`, `#RRGGBB` or `#RRGGBBAA` hex color
+fn parse_hex(hex: &str) -> Result<Color, String> {
+    let digits = |range: std::ops::Range<usize>| {
+        hex.get(range)
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| format!("invalid hex color `#{hex}`"))
+    };
+
+    match hex.len() {
+        3 => {
+            let short = |index: usize| digits(index..index + 1).map(|value| value * 17);
+            Ok(Color::from_rgb8(short(0)?, short(1)?, short(2)?))
+        }
+        6 => Ok(Color::from_rgb8(digits(0..2)?, digits(2..4)?, digits(4..6)?)),
+        8 => Ok(Color::from_rgba8(
+            digits(0..2)?,
+            digits(2..4)?,
+            digits(4..6)?,
+            f32::from(digits(6..8)?) / 255.0,
+        )),
+        _ => Err(format!("invalid hex color `#{hex}` (expected 3, 6 or 8 digits)")),
+    }
+}
+
+impl fmt::Display for FileColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [r, g, b, a] = self.0.into_rgba8();
+
+        if a == u8::MAX {
+            write!(f, "#{r:02X}{g:02X}{b:02X}")
+        } else {
+            write!(f, "#{r:02X}{g:02X}{b:02X}{a:02X}")
+        }
+    }
+}
+
+impl Serialize for FileColor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FileColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parsing inside the visitor lets JSON errors point at the value's line
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = FileColor;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a `#RRGGBB` hex string or `rgba(...)`")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<FileColor, E> {
+                FileColor::parse(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+/// Name of a [`ThemeColors`] role, validated against [`COLOR_ROLES`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct ColorRole(String);
+
+impl ColorRole {
+    /// The name of the role
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorRole {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let role = String::deserialize(deserializer)?;
+
+        if COLOR_ROLES.contains(&role.as_str()) {
+            Ok(ColorRole(role))
+        } else {
+            Err(serde::de::Error::unknown_field(&role, COLOR_ROLES))
+        }
+    }
+}
+
+/// Corner radii section of a theme file
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RadiiSection {
+    /// Radius of buttons and search fields
+    pub corner: f32,
+    /// Radius of text inputs and pick lists
+    pub small: f32,
+    /// Radius of checkboxes and menus
+    pub tiny: f32,
+    /// Radius of cards, groups, floating panels and panes
+    pub card: f32,
+    /// Radius of sheets
+    pub sheet: f32,
+}
+
+impl Default for RadiiSection {
+    fn default() -> Self {
+        let metrics = Metrics::default();
+
+        Self {
+            corner: metrics.corner_radius,
+            small: metrics.small_corner_radius,
+            tiny: metrics.tiny_corner_radius,
+            card: metrics.card_corner_radius,
+            sheet: metrics.sheet_corner_radius,
+        }
+    }
+}
+
+/// Control shadow section of a theme file
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ShadowSection {
+    pub color: FileColor,
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub blur_radius: f32,
+}
+
+impl Default for ShadowSection {
+    fn default() -> Self {
+        Self::from(Metrics::default().shadow)
+    }
+}
+
+impl From<Shadow> for ShadowSection {
+    fn from(shadow: Shadow) -> Self {
+        Self {
+            color: FileColor(shadow.color),
+            offset_x: shadow.offset.x,
+            offset_y: shadow.offset.y,
+            blur_radius: shadow.blur_radius,
+        }
+    }
+}
+
+impl From<ShadowSection> for Shadow {
+    fn from(section: ShadowSection) -> Self {
+        Shadow {
+            color: section.color.0,
+            offset: Vector::new(section.offset_x, section.offset_y),
+            blur_radius: section.blur_radius,
+        }
+    }
+}
+
+/// Serializable description of a Modern theme
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThemeFile {
+    /// Name of the theme
+    pub name: String,
+    /// Light or dark mode, defaults to light
+    #[serde(default)]
+    pub mode: Mode,
+    /// High contrast styling, defaults to off
+    #[serde(default)]
+    pub high_contrast: bool,
+    /// Overridden color roles
+    #[serde(default)]
+    pub colors: BTreeMap<ColorRole, FileColor>,
+    /// Corner radii
+    #[serde(default)]
+    pub radii: RadiiSection,
+    /// Control shadow
+    #[serde(default)]
+    pub shadow: ShadowSection,
+    /// Hovered and pressed lightness deltas
+    #[serde(default)]
+    pub states: StateDeltas,
+}
+
+impl ThemeFile {
+    /// Describe an existing theme, writing out every color role
+    pub fn from_theme(theme: &Theme) -> Self {
+        let mut colors = get_theme_colors(theme);
+        let metrics = get_theme_metrics(theme);
+
+        Self {
+            name: theme.to_string(),
+            mode: theme_mode(theme),
+            high_contrast: is_high_contrast(theme),
+            colors: COLOR_ROLES
+                .iter()
+                .filter_map(|role| {
+                    color_role(&mut colors, role)
+                        .map(|color| (ColorRole(role.to_string()), FileColor(*color)))
+                })
+                .collect(),
+            radii: RadiiSection {
+                corner: metrics.corner_radius,
+                small: metrics.small_corner_radius,
+                tiny: metrics.tiny_corner_radius,
+                card: metrics.card_corner_radius,
+                sheet: metrics.sheet_corner_radius,
+            },
+            shadow: ShadowSection::from(metrics.shadow),
+            states: metrics.states,
+        }
+    }
+
+    /// Parse a theme file in the given format
+    pub fn from_str(source: &str, format: ThemeFormat) -> Result<Self, ThemeFileError> {
+        match format {
+            ThemeFormat::Toml => {
+                let deserializer = toml::Deserializer::parse(source)
+                    .map_err(|error| toml_error(source, None, &error))?;
+
+                serde_path_to_error::deserialize(deserializer).map_err(|error| {
+                    let key = error.path().to_string();
+                    toml_error(source, Some(key), error.inner())
+                })
+            }
+            ThemeFormat::Json => {
+                let mut deserializer = serde_json::Deserializer::from_str(source);
+
+                serde_path_to_error::deserialize(&mut deserializer).map_err(|error| {
+                    let key = error.path().to_string();
+                    let inner = error.into_inner();
+
+                    ThemeFileError::Parse {
+                        key: Some(key).filter(|key| key != "."),
+                        line: Some(inner.line()).filter(|line| *line > 0),
+                        message: json_message(&inner),
+                    }
+                })
+            }
+        }
+    }
+
+    /// Read a theme file, picking the format from its extension
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ThemeFileError> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)?;
+
+        Self::from_str(&source, ThemeFormat::from_path(path))
+    }
+
+    /// Write the theme in the given format
+    pub fn to_string(&self, format: ThemeFormat) -> Result<String, ThemeFileError> {
+        match format {
+            ThemeFormat::Toml => toml::to_string_pretty(self)
+                .map_err(|error| ThemeFileError::Serialize(error.to_string())),
+            ThemeFormat::Json => serde_json::to_string_pretty(self)
+                .map_err(|error| ThemeFileError::Serialize(error.to_string())),
+        }
+    }
+
+    /// Write the theme to a file, picking the format from its extension
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ThemeFileError> {
+        let path = path.as_ref();
+        let contents = self.to_string(ThemeFormat::from_path(path))?;
+
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// The palette described by the file
+    ///
+    /// The accent is applied first, so its derived focus ring, selection and
+    /// link colors can still be overridden by the other roles.
+    pub fn colors(&self) -> ThemeColors {
+        let mut colors = if self.high_contrast {
+            ThemeColors::high_contrast(self.mode)
+        } else {
+            ThemeColors::new(self.mode, Accent::SYSTEM)
+        };
+
+        if let Some(accent) = self.colors.get(&ColorRole("accent".to_string())) {
+            colors = colors.with_accent(accent.0);
+        }
+
+        for (role, color) in &self.colors {
+            if let Some(slot) = color_role(&mut colors, role.as_str()) {
+                *slot = color.0;
+            }
+        }
+
+        colors
+    }
+
+    /// The corner radii, control shadow and state deltas described by the file
+    pub fn metrics(&self) -> Metrics {
+        Metrics {
+            corner_radius: self.radii.corner,
+            small_corner_radius: self.radii.small,
+            tiny_corner_radius: self.radii.tiny,
+            card_corner_radius: self.radii.card,
+            sheet_corner_radius: self.radii.sheet,
+            shadow: self.shadow.into(),
+            states: self.states,
+        }
+    }
+
+    /// Build the Modern theme described by the file
+    pub fn to_modern_theme(&self) -> ModernTheme {
+        ModernTheme::new(self.name.clone(), self.mode)
+            .with_colors(self.colors())
+            .with_metrics(self.metrics())
+            .with_high_contrast(self.high_contrast)
+    }
+}
+
+/// Convert a TOML error into a [`ThemeFileError`] with its line number
+fn toml_error(source: &str, key: Option<String>, error: &toml::de::Error) -> ThemeFileError {
+    let line = error
+        .span()
+        .map(|span| source[..span.start.min(source.len())].matches('\n').count() + 1);
+
+    ThemeFileError::Parse {
+        key: key.filter(|key| key != "."),
+        line,
+        message: error.message().to_string(),
+    }
+}
+
+/// Strip the position suffix serde_json appends to its messages
+fn json_message(error: &serde_json::Error) -> String {
+    let message = error.to_string();
+    let suffix = format!(" at line {} column {}", error.line(), error.column());
+
+    message
+        .strip_suffix(&suffix)
+        .map(str::to_string)
+        .unwrap_or(message)
+}
+
+/// Default polling interval of [`watch`]
+pub const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Notification produced by [`watch`] when a theme file changes
+#[derive(Debug, Clone)]
+pub enum ThemeEvent {
+    /// The file was loaded successfully
+    Loaded(Theme),
+    /// The file could not be read or parsed
+    ///
+    /// No theme is emitted, so the application keeps its last good theme.
+    Failed(Arc<ThemeFileError>),
+}
+
+impl ThemeEvent {
+    /// Replace the current theme with a loaded one, keeping it on failure
+    pub fn apply(self, theme: &mut Theme) -> Result<(), Arc<ThemeFileError>> {
+        match self {
+            ThemeEvent::Loaded(loaded) => {
+                *theme = loaded;
+                Ok(())
+            }
+            ThemeEvent::Failed(error) => Err(error),
+        }
+    }
+}
+
+/// Watch a theme file, emitting a [`ThemeEvent`] on start and whenever it changes
+///
+/// The file is polled on a background thread at the given interval, which
+/// works with every iced executor. Polling stops once the subscription is dropped.
+pub fn watch(path: impl Into<PathBuf>, interval: Duration) -> Subscription<ThemeEvent> {
+    Subscription::run_with((path.into(), interval), poll)
+}
+
+/// Poll the modification time of a theme file and reload it when it changes
+fn poll((path, interval): &(PathBuf, Duration)) -> impl Stream<Item = ThemeEvent> {
+    let (path, interval) = (path.clone(), *interval);
+    let (sender, receiver) = mpsc::unbounded();
+
+    std::thread::spawn(move || {
+        let mut last_seen = None;
+
+        while !sender.is_closed() {
+            let modified = std::fs::metadata(&path)
+                .and_then(|metadata| metadata.modified())
+                .map_err(|error| error.kind());
+
+            if last_seen.as_ref() != Some(&modified) {
+                last_seen = Some(modified);
+
+                let event = match ThemeFile::load(&path) {
+                    Ok(file) => ThemeEvent::Loaded(file.to_modern_theme().theme()),
+                    Err(error) => ThemeEvent::Failed(Arc::new(error)),
+                };
+
+                if sender.unbounded_send(event).is_err() {
+                    break;
+                }
+            }
+
+            std::thread::sleep(interval);
+        }
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::Modern;
+    use iced::widget::container;
+
+    const SOURCE: &str = r##"name = "Brand Dark"
+mode = "dark"
+
+[colors]
+accent = "#7D3CFF"
+background = "rgb(21, 19, 26)"
+separator = "#FFFFFF80"
+link = "rgba(0, 122, 255, 0.6)"
+
+[radii]
+corner = 10.0
+
+[shadow]
+color = "#0000001A"
+"##;
+
+    /// A path in the temp directory unique to this test run
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("iced_modern_theme_{}_{name}", std::process::id()))
+    }
+
+    fn parse_error(source: &str, format: ThemeFormat) -> (Option<String>, Option<usize>, String) {
+        match ThemeFile::from_str(source, format) {
+            Err(ThemeFileError::Parse { key, line, message }) => (key, line, message),
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parses_hex_colors() {
+        assert_eq!(FileColor::parse("#007AFF"), Ok(FileColor(Color::from_rgb8(0, 122, 255))));
+        assert_eq!(FileColor::parse("#fff"), Ok(FileColor(Color::WHITE)));
+        assert_eq!(
+            FileColor::parse(" #007AFF80 "),
+            Ok(FileColor(Color::from_rgba8(0, 122, 255, 128.0 / 255.0))),
+        );
+    }
+
+    #[test]
+    fn parses_rgb_colors() {
+        assert_eq!(FileColor::parse("rgb(0, 122, 255)"), Ok(FileColor(Color::from_rgb8(0, 122, 255))));
+        assert_eq!(
+            FileColor::parse("rgba(0,0,0,0.5)"),
+            Ok(FileColor(Color::from_rgba8(0, 0, 0, 0.5))),
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_colors() {
+        for value in [
+            "",
+            "blue",
+            "#12345",
+            "#GGGGGG",
+            "#007AFF8",
+            "rgb(0, 122)",
+            "rgb(0, 122, 256)",
+            "rgb(0, 122, 255",
+            "rgba(0, 122, 255)",
+            "rgba(0, 122, 255, 1.5)",
+            "rgba(0, 122, 255, half)",
+        ] {
+            assert!(FileColor::parse(value).is_err(), "`{value}` should not parse");
+        }
+    }
+
+    #[test]
+    fn displays_colors_as_hex() {
+        assert_eq!(FileColor(Color::from_rgb8(0, 122, 255)).to_string(), "#007AFF");
+        assert_eq!(FileColor(Color::from_rgba8(0, 122, 255, 0.5)).to_string(), "#007AFF80");
+
+        for value in ["#007AFF", "#7D3CFF80"] {
+            assert_eq!(FileColor::parse(value).unwrap().to_string(), value);
+        }
+    }
+
+    #[test]
+    fn applies_color_roles() {
+        let file = ThemeFile::from_str(SOURCE, ThemeFormat::Toml).unwrap();
+        let colors = file.colors();
+
+        assert_eq!(file.mode, Mode::Dark);
+        assert_eq!(colors.accent, Color::from_rgb8(0x7D, 0x3C, 0xFF));
+        assert_eq!(colors.background, Color::from_rgb8(21, 19, 26));
+        assert_eq!(colors.link, Color::from_rgba8(0, 122, 255, 0.6));
+        assert_eq!(colors.text, ThemeColors::dark().text);
+        assert_eq!(file.metrics().corner_radius, 10.0);
+    }
+
+    #[test]
+    fn applies_radii_to_containers() {
+        let source = "name = \"Square\"\n\n[radii]\ncard = 0.0\nsheet = 2.0\n";
+        let theme = ThemeFile::from_str(source, ThemeFormat::Toml).unwrap().to_modern_theme().theme();
+
+        let radius = |style: container::Style| style.border.radius.top_left;
+
+        assert_eq!(radius(Modern::card_container()(&theme)), 0.0);
+        assert_eq!(radius(Modern::floating_container()(&theme)), 0.0);
+        assert_eq!(radius(Modern::pane_body_container()(&theme)), 0.0);
+        assert_eq!(radius(Modern::sheet_container()(&theme)), 2.0);
+    }
+
+    #[test]
+    fn round_trips_through_files() {
+        // Hex colors survive exactly; other alphas are rounded to 8 bits
+        let mut file = ThemeFile::from_str(SOURCE, ThemeFormat::Toml).unwrap();
+        file.colors.remove(&ColorRole("link".to_string()));
+
+        for name in ["theme.toml", "theme.json"] {
+            let path = temp_path(name);
+
+            file.save(&path).unwrap();
+            let loaded = ThemeFile::load(&path);
+            let _ = std::fs::remove_file(&path);
+
+            assert_eq!(loaded.unwrap(), file, "{name} should load what was saved");
+        }
+    }
+
+    #[test]
+    fn round_trips_existing_themes() {
+        let theme = ModernTheme::dark().with_accent(Color::from_rgb8(0x7D, 0x3C, 0xFF)).theme();
+
+        for format in [ThemeFormat::Toml, ThemeFormat::Json] {
+            let written = ThemeFile::from_theme(&theme).to_string(format).unwrap();
+            let reloaded = ThemeFile::from_str(&written, format).unwrap();
+
+            assert_eq!(reloaded.to_string(format).unwrap(), written);
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_roles() {
+        let source = "name = \"Broken\"\n\n[colors]\nshiny = \"#FFFFFF\"\n";
+        let (_, line, message) = parse_error(source, ThemeFormat::Toml);
+
+        assert_eq!(line, Some(4));
+        assert!(message.contains("shiny"), "unexpected message: {message}");
+
+        let source = "{\n  \"name\": \"Broken\",\n  \"colors\": {\n    \"shiny\": \"#FFFFFF\"\n  }\n}\n";
+        let (_, line, message) = parse_error(source, ThemeFormat::Json);
+
+        assert_eq!(line, Some(4));
+        assert!(message.contains("shiny"), "unexpected message: {message}");
+    }
+
+    #[test]
+    fn reports_key_and_line_of_bad_toml_values() {
+        let source = "name = \"Broken\"\nmode = \"dark\"\n\n[colors]\naccent = \"#12345\"\n";
+        let (key, line, message) = parse_error(source, ThemeFormat::Toml);
+
+        assert_eq!(key.as_deref(), Some("colors.accent"));
+        assert_eq!(line, Some(5));
+        assert!(message.contains("#12345"), "unexpected message: {message}");
+    }
+
+    #[test]
+    fn reports_key_and_line_of_bad_json_values() {
+        let source = "{\n  \"name\": \"Broken\",\n  \"colors\": {\n    \"accent\": \"rgb(0, 0)\"\n  }\n}\n";
+        let (key, line, message) = parse_error(source, ThemeFormat::Json);
+
+        assert_eq!(key.as_deref(), Some("colors.accent"));
+        assert_eq!(line, Some(4));
+        assert!(message.contains("components"), "unexpected message: {message}");
+    }
+}
@@ -0,0 +1,148 @@
+//! Tonal scales generated from a single seed color.
+//!
+//! A [`TonalScale`] is an 11-step ramp from 50 (lightest) to 950 (darkest)
+//! that keeps the hue of its seed while stepping through evenly spaced OKLCH
+//! lightness values. Custom widgets can pick tints from the scale instead of
+//! fading colors with `scale_alpha`, and [`TonalScale::theme_colors`] derives
+//! a complete [`ThemeColors`] palette from it.
+//!
+//! ```
+//! use iced::Color;
+//! use iced_modern_theme::{Mode, TonalScale};
+//!
+//! let brand = TonalScale::from_seed(Color::from_rgb8(0x7D, 0x3C, 0xFF));
+//!
+//! let hover_fill = brand.tone(100);
+//! let colors = brand.theme_colors(Mode::Dark);
+//! ```
+
+use iced::Color;
+use palette::{FromColor, Oklch, Srgb};
+
+use crate::colors::{from_oklch, Mode, ThemeColors};
+
+/// Steps of a tonal scale, from lightest to darkest
+pub const STEPS: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// OKLCH lightness of each step
+const LIGHTNESS: [f32; 11] = [0.97, 0.93, 0.87, 0.79, 0.71, 0.63, 0.55, 0.47, 0.39, 0.31, 0.24];
+
+/// Share of the seed's chroma kept by each step; the ends of the scale are
+/// desaturated so the lightest and darkest tones stay usable as surfaces
+const CHROMA: [f32; 11] = [0.15, 0.3, 0.5, 0.75, 0.92, 1.0, 1.0, 0.92, 0.8, 0.66, 0.52];
+
+/// Chroma of the neutral scale, just enough to carry the seed's hue
+const NEUTRAL_CHROMA: f32 = 0.012;
+
+/// An 11-step tonal ramp sharing the hue of a seed color
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TonalScale {
+    seed: Color,
+    tones: [Color; 11],
+}
+
+impl TonalScale {
+    /// Generate the scale of a seed color
+    ///
+    /// Each step keeps the seed's hue and a share of its chroma. Tones that
+    /// fall outside sRGB lose chroma rather than shifting hue.
+    pub fn from_seed(seed: Color) -> Self {
+        let oklch = Oklch::from_color(Srgb::new(seed.r, seed.g, seed.b));
+
+        Self::generate(seed, |step| Oklch {
+            l: LIGHTNESS[step],
+            chroma: oklch.chroma * CHROMA[step],
+            ..oklch
+        })
+    }
+
+    /// Generate a near-gray scale tinted with the hue of a seed color
+    ///
+    /// Used for backgrounds, borders and separators of seeded themes.
+    pub fn neutral(seed: Color) -> Self {
+        let oklch = Oklch::from_color(Srgb::new(seed.r, seed.g, seed.b));
+        let chroma = oklch.chroma.min(NEUTRAL_CHROMA);
+
+        Self::generate(seed, |step| Oklch {
+            l: LIGHTNESS[step],
+            chroma,
+            ..oklch
+        })
+    }
+
+    fn generate(seed: Color, tone: impl Fn(usize) -> Oklch) -> Self {
+        Self {
+            seed,
+            tones: std::array::from_fn(|step| from_oklch(tone(step), 1.0)),
+        }
+    }
+
+    /// The color the scale was generated from
+    pub fn seed(&self) -> Color {
+        self.seed
+    }
+
+    /// The tone of a step (50, 100, 200, ... 900, 950)
+    ///
+    /// Steps in between resolve to the nearest step of the scale.
+    pub fn tone(&self, step: u16) -> Color {
+        let index = STEPS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, candidate)| candidate.abs_diff(step))
+            .map(|(index, _)| index)
+            .unwrap_or_default();
+
+        self.tones[index]
+    }
+
+    /// Every step with its tone, from lightest to darkest
+    pub fn tones(&self) -> impl Iterator<Item = (u16, Color)> + '_ {
+        STEPS.into_iter().zip(self.tones)
+    }
+
+    /// Derive a complete palette for a mode from the scale
+    ///
+    /// The accent comes from the scale (600 in light mode, 500 in dark mode)
+    /// and backgrounds, fills and borders come from the matching
+    /// [`neutral`](Self::neutral) scale. In dark mode the window uses the
+    /// darkest neutral and cards sit one step lighter. Text and the system
+    /// colors keep their defaults.
+    pub fn theme_colors(&self, mode: Mode) -> ThemeColors {
+        let neutral = Self::neutral(self.seed);
+
+        let builder = ThemeColors::builder(mode);
+        let builder = match mode {
+            Mode::Light => builder
+                .accent(self.tone(600))
+                .secondary_background(neutral.tone(50))
+                .tertiary_background(neutral.tone(100))
+                .sheet_bg(neutral.tone(50))
+                .group_bg(neutral.tone(50))
+                .sidebar_bg(neutral.tone(100))
+                .system_bg(neutral.tone(200))
+                .inactive_border(neutral.tone(300))
+                .input_bg(neutral.tone(50))
+                .input_border(neutral.tone(300))
+                .separator(neutral.tone(200))
+                .gray(neutral.tone(400)),
+            Mode::Dark => builder
+                .accent(self.tone(500))
+                .background(neutral.tone(950))
+                .secondary_background(neutral.tone(900))
+                .tertiary_background(neutral.tone(800))
+                .card_bg(neutral.tone(900))
+                .sheet_bg(neutral.tone(800))
+                .group_bg(neutral.tone(900))
+                .sidebar_bg(neutral.tone(900))
+                .system_bg(neutral.tone(800))
+                .inactive_border(neutral.tone(700))
+                .input_bg(neutral.tone(900))
+                .input_border(neutral.tone(700))
+                .separator(neutral.tone(800))
+                .gray(neutral.tone(600)),
+        };
+
+        builder.build()
+    }
+}